@@ -0,0 +1,135 @@
+//! Compiles the imgui shaders in `shaders/` with bgfx's shaderc and embeds the blobs.
+//!
+//! shaderc is taken from the `SHADERC` environment variable, or from `PATH`. Profiles it can't
+//! build, because there is no shaderc or because e.g. the Direct3D compilers only exist on
+//! Windows, use the blobs checked in under `shaders/bin/<profile>/` instead, so the crate
+//! builds without any shader tooling. The build never writes to the source tree, the checked-in
+//! blobs are refreshed with `shaders/update_blobs.sh`.
+//!
+//! Every shader ends up as `$OUT_DIR/<shader>.rs` with one constant per profile, e.g.
+//! `VS_OCORNUT_IMGUI_SPV`, included by the matching module of `imgui_bgfx_renderer`. Only the
+//! profiles of enabled backend features are embedded, the others are `None`. GLSL is always
+//! embedded since the Noop backend needs a blob too.
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+const SHADERS: [(&str, ShaderType); 2] = [("vs_ocornut_imgui", ShaderType::Vertex), ("fs_ocornut_imgui", ShaderType::Fragment)];
+
+#[derive(Clone, Copy, PartialEq)]
+enum ShaderType {
+    Vertex,
+    Fragment
+}
+
+struct Profile {
+    /// Directory of the blobs below `shaders/bin` and `$OUT_DIR`.
+    dir : &'static str,
+    /// Suffix of the generated constant.
+    suffix : &'static str,
+    /// Backend feature that embeds the profile, `None` if it is always embedded.
+    feature : Option<&'static str>,
+    /// The Direct3D profiles need d3dcompiler, which shaderc only has on Windows.
+    windows_only : bool
+}
+
+const PROFILES: [Profile; 6] = [
+    Profile { dir: "glsl", suffix: "GLSL", feature: None, windows_only: false },
+    Profile { dir: "essl", suffix: "ESSL", feature: Some("OPENGLES"), windows_only: false },
+    Profile { dir: "spirv", suffix: "SPV", feature: Some("VULKAN"), windows_only: false },
+    Profile { dir: "dx9", suffix: "DX9", feature: Some("DIRECT3D9"), windows_only: true },
+    Profile { dir: "dx11", suffix: "DX11", feature: Some("DIRECT3D11"), windows_only: true },
+    Profile { dir: "metal", suffix: "MTL", feature: Some("METAL"), windows_only: false },
+];
+
+impl Profile {
+    /// Whether the crate is built with the profile's backend feature.
+    fn enabled(&self) -> bool {
+        self.feature.is_none_or(|feature| env::var_os(format!("CARGO_FEATURE_{}", feature)).is_some())
+    }
+    /// Same arguments as `shaders/update_blobs.sh`, which makes the checked-in blobs.
+    fn shaderc_args(&self, shader_type : ShaderType) -> Vec<&'static str> {
+        let stage = |vertex, fragment| if shader_type == ShaderType::Vertex { vertex } else { fragment };
+        match self.dir {
+            "glsl" => vec!["--platform", "linux"],
+            "essl" => vec!["--platform", "android"],
+            "spirv" => vec!["--platform", "linux", "-p", "spirv"],
+            "dx9" => vec!["--platform", "windows", "-p", stage("vs_3_0", "ps_3_0"), "-O", "3"],
+            "dx11" => vec!["--platform", "windows", "-p", stage("vs_4_0", "ps_4_0"), "-O", "3"],
+            "metal" => vec!["--platform", "ios", "-p", "metal", "-O", "3"],
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// `SHADERC` if set, else `shaderc` if it can be run from `PATH`.
+fn find_shaderc() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("SHADERC") {
+        return Some(PathBuf::from(path));
+    }
+    let found = Command::new("shaderc").arg("--version").stdout(Stdio::null()).stderr(Stdio::null()).status().is_ok();
+    found.then(|| PathBuf::from("shaderc"))
+}
+
+fn compile(shaderc : &Path, shaders_dir : &Path, name : &str, shader_type : ShaderType, profile : &Profile, output : &Path) -> Result<(), String> {
+    let output_result = Command::new(shaderc)
+        .arg("-f").arg(shaders_dir.join(format!("{}.sc", name)))
+        .arg("-o").arg(output)
+        .arg("--type").arg(if shader_type == ShaderType::Vertex { "vertex" } else { "fragment" })
+        .arg("-i").arg(shaders_dir.join("include"))
+        .arg("--varyingdef").arg(shaders_dir.join("varying.def.sc"))
+        .args(profile.shaderc_args(shader_type))
+        .output()
+        .map_err(|e| format!("failed to run {}: {}", shaderc.display(), e))?;
+    if !output_result.status.success() {
+        return Err(String::from_utf8_lossy(&output_result.stderr).trim().to_string());
+    }
+    Ok(())
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let shaders_dir = manifest_dir.join("shaders");
+    for input in ["varying.def.sc", "include/bgfx_shader.sh"] {
+        println!("cargo:rerun-if-changed={}", shaders_dir.join(input).display());
+    }
+    println!("cargo:rerun-if-env-changed=SHADERC");
+    let shaderc = find_shaderc();
+
+    for (name, shader_type) in SHADERS {
+        println!("cargo:rerun-if-changed={}", shaders_dir.join(format!("{}.sc", name)).display());
+        let mut module = format!("// Generated by build.rs from shaders/{}.sc\n", name);
+        for profile in &PROFILES {
+            let constant = format!("{}_{}", name.to_uppercase(), profile.suffix);
+            if !profile.enabled() {
+                module += &format!("pub const {} : Option<&[u8]> = None;\n", constant);
+                continue;
+            }
+            let precompiled = shaders_dir.join("bin").join(profile.dir).join(format!("{}.bin", name));
+            println!("cargo:rerun-if-changed={}", precompiled.display());
+            let output = out_dir.join(profile.dir).join(format!("{}.bin", name));
+            fs::create_dir_all(output.parent().unwrap()).unwrap();
+            let compiled = match &shaderc {
+                Some(shaderc) if !profile.windows_only || cfg!(windows) => match compile(shaderc, &shaders_dir, name, shader_type, profile, &output) {
+                    Ok(()) => true,
+                    Err(e) => {
+                        println!("cargo:warning=shaderc failed on {} for {}, using the precompiled blob: {}", name, profile.dir, e);
+                        false
+                    }
+                },
+                _ => false,
+            };
+            if !compiled {
+                fs::copy(&precompiled, &output).unwrap_or_else(|e| panic!("{}: {}", precompiled.display(), e));
+            }
+            let blob = format!("include_bytes!({:?})", output.display().to_string());
+            module += &match profile.feature {
+                Some(_) => format!("pub const {} : Option<&[u8]> = Some({});\n", constant, blob),
+                None => format!("pub const {} : &[u8] = {};\n", constant, blob),
+            };
+        }
+        fs::write(out_dir.join(format!("{}.rs", name)), module).unwrap();
+    }
+}
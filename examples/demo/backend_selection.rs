@@ -0,0 +1,176 @@
+//! Chooses the bgfx renderer backend for the windowed loop.
+//!
+//! The backend comes from `--renderer opengl|vulkan|noop|auto`, then from the `renderer` key
+//! of the config file, then defaults to `auto`. Since a backend can fail to initialize on a
+//! given machine (e.g. no Vulkan driver), startup walks a preference list that starts with the
+//! chosen backend and continues with the platform's defaults.
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use bgfx_rs::bgfx::RendererType;
+use bgfx_imgui_glfw_rs::imgui_bgfx_renderer;
+
+/// Read from the working directory when `--config` isn't given, a missing file is fine.
+pub const DEFAULT_CONFIG_PATH: &str = "bgfx-imgui-glfw-rs-example.cfg";
+
+#[cfg(target_os = "linux")]
+const PLATFORM_PREFERENCES: &[RendererType] = &[RendererType::OpenGL, RendererType::Vulkan];
+
+/// `Count` lets bgfx pick the platform's best backend.
+#[cfg(not(target_os = "linux"))]
+const PLATFORM_PREFERENCES: &[RendererType] = &[RendererType::Count, RendererType::Vulkan, RendererType::OpenGL];
+
+/// Why no backend could be chosen from the command line and config file.
+#[derive(Debug)]
+pub enum ConfigError {
+    Io { path : PathBuf, source : io::Error },
+    /// A line that isn't `key = value`, `path` is `None` for text that didn't come from a file.
+    Syntax { path : Option<PathBuf>, line : usize },
+    UnknownRenderer(String),
+    /// The renderer's cargo feature is disabled in this build.
+    DisabledRenderer(String)
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            ConfigError::Syntax { path: Some(path), line } => write!(f, "{}: line {}: expected key = value", path.display(), line),
+            ConfigError::Syntax { path: None, line } => write!(f, "line {}: expected key = value", line),
+            ConfigError::UnknownRenderer(name) => write!(f, "unknown renderer '{}', expected opengl, vulkan, noop or auto", name),
+            ConfigError::DisabledRenderer(name) => write!(f, "renderer '{}' is disabled in this build, enable its cargo feature", name),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BackendChoice {
+    Auto,
+    OpenGL,
+    Vulkan,
+    Noop
+}
+
+impl BackendChoice {
+    pub fn parse(name : &str) -> Result<BackendChoice, ConfigError> {
+        let choice = match name.to_ascii_lowercase().as_str() {
+            "auto" => BackendChoice::Auto,
+            "opengl" => BackendChoice::OpenGL,
+            "vulkan" => BackendChoice::Vulkan,
+            "noop" => BackendChoice::Noop,
+            _ => return Err(ConfigError::UnknownRenderer(name.to_string())),
+        };
+        match choice.renderer_type() {
+            Some(renderer_type) if !imgui_bgfx_renderer::supports(renderer_type) => Err(ConfigError::DisabledRenderer(name.to_string())),
+            _ => Ok(choice),
+        }
+    }
+
+    /// `None` for `Auto`.
+    fn renderer_type(self) -> Option<RendererType> {
+        match self {
+            BackendChoice::Auto => None,
+            BackendChoice::OpenGL => Some(RendererType::OpenGL),
+            BackendChoice::Vulkan => Some(RendererType::Vulkan),
+            BackendChoice::Noop => Some(RendererType::Noop),
+        }
+    }
+
+    /// Backends to try in order, the explicitly chosen one first. Platform defaults whose
+    /// feature is disabled are left out.
+    pub fn preferences(self) -> Vec<RendererType> {
+        let defaults = PLATFORM_PREFERENCES.iter().copied().filter(|renderer_type| *renderer_type == RendererType::Count || imgui_bgfx_renderer::supports(*renderer_type));
+        let first = match self {
+            BackendChoice::Auto => return defaults.collect(),
+            // Noop never fails and is only useful when asked for, nothing to fall back to
+            BackendChoice::Noop => return vec![RendererType::Noop],
+            BackendChoice::OpenGL => RendererType::OpenGL,
+            BackendChoice::Vulkan => RendererType::Vulkan,
+        };
+        let mut preferences = vec![first];
+        preferences.extend(defaults.filter(|renderer_type| *renderer_type != first));
+        preferences
+    }
+}
+
+/// `key = value` lines, blank lines and lines starting with `#` are ignored.
+#[derive(Default)]
+pub struct Config {
+    values : HashMap<String, String>
+}
+
+impl Config {
+    pub fn parse(text : &str) -> Result<Config, ConfigError> {
+        let mut values = HashMap::new();
+        for (line_index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or(ConfigError::Syntax { path: None, line: line_index + 1 })?;
+            values.insert(key.trim().to_string(), value.trim().to_string());
+        }
+        Ok(Config { values })
+    }
+    pub fn load<P : AsRef<Path>>(path : P) -> Result<Config, ConfigError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|source| ConfigError::Io { path: path.to_path_buf(), source })?;
+        Config::parse(&text).map_err(|e| match e {
+            ConfigError::Syntax { line, .. } => ConfigError::Syntax { path: Some(path.to_path_buf()), line },
+            e => e,
+        })
+    }
+    pub fn get(&self, key : &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+}
+
+/// The command line wins over the config file, both missing means `Auto`.
+pub fn choose_backend(cli_value : Option<&str>, config : &Config) -> Result<BackendChoice, ConfigError> {
+    match cli_value.or_else(|| config.get("renderer")) {
+        Some(name) => BackendChoice::parse(name),
+        None => Ok(BackendChoice::Auto),
+    }
+}
+
+#[cfg(test)]
+// the expectations assume the default backend features
+#[cfg(all(feature = "opengl", feature = "vulkan"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_line_overrides_the_config_file() {
+        let config = Config::parse("# renderer settings\n\n renderer = Vulkan \nvsync=1\n").unwrap();
+        assert_eq!(config.get("vsync"), Some("1"));
+        assert_eq!(choose_backend(None, &config).unwrap(), BackendChoice::Vulkan);
+        assert_eq!(choose_backend(Some("noop"), &config).unwrap(), BackendChoice::Noop);
+        assert_eq!(choose_backend(None, &Config::default()).unwrap(), BackendChoice::Auto);
+        assert!(matches!(choose_backend(Some("metal"), &config), Err(ConfigError::UnknownRenderer(name)) if name == "metal"));
+        assert!(matches!(Config::parse("renderer = opengl\nrenderer\n"), Err(ConfigError::Syntax { path: None, line: 2 })));
+    }
+
+    #[test]
+    fn falls_back_to_the_platform_defaults() {
+        assert_eq!(BackendChoice::Auto.preferences(), PLATFORM_PREFERENCES);
+        assert_eq!(BackendChoice::Noop.preferences(), [RendererType::Noop]);
+        for choice in [BackendChoice::OpenGL, BackendChoice::Vulkan] {
+            let preferences = choice.preferences();
+            assert_eq!(preferences[0], if choice == BackendChoice::OpenGL { RendererType::OpenGL } else { RendererType::Vulkan });
+            assert_eq!(preferences.len(), 1 + PLATFORM_PREFERENCES.iter().filter(|renderer_type| **renderer_type != preferences[0]).count());
+            for renderer_type in PLATFORM_PREFERENCES {
+                assert_eq!(preferences.iter().filter(|p| *p == renderer_type).count(), 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,429 @@
+mod backend_selection;
+
+use bgfx::*;
+use bgfx_imgui_glfw_rs::bgfx_rs::bgfx;
+use bgfx_imgui_glfw_rs::{glfw, imgui};
+use bgfx_imgui_glfw_rs::{draw_capture, headless, imgui_bgfx_renderer, software_rasterizer};
+use glfw::{Action, Key, Window};
+use imgui::Context;
+use raw_window_handle::HasRawWindowHandle;
+use std::process::ExitCode;
+use bgfx_imgui_glfw_rs::{BgfxInstance, Error, GlfwPlatform, Result};
+use bgfx_imgui_glfw_rs::imgui_glfw_support::DisplayScale;
+use bgfx_imgui_glfw_rs::imgui_glfw_support::clipboard::GlfwClipboard;
+use bgfx_imgui_glfw_rs::imgui_glfw_support::recording::{InputPlayer, InputRecorder};
+use backend_selection::{BackendChoice, Config, ConfigError, DEFAULT_CONFIG_PATH};
+
+const DEFAULT_WIDTH: u32 = 1920;
+const DEFAULT_HEIGHT: u32 = 1080;
+
+/// `--renderer`, else the `renderer` key of `--config` or the default config file.
+fn backend_choice() -> std::result::Result<BackendChoice, ConfigError> {
+    let config = match arg_value("--config") {
+        Some(path) => Config::load(&path)?,
+        None if std::path::Path::new(DEFAULT_CONFIG_PATH).exists() => Config::load(DEFAULT_CONFIG_PATH)?,
+        None => Config::default(),
+    };
+    backend_selection::choose_backend(arg_value("--renderer").as_deref(), &config)
+}
+
+/// Tries the backends of `choice` in order of preference and keeps the first that initializes.
+fn init_bgfx(window: &Window, choice: BackendChoice) -> Result<BgfxInstance> {
+    let preferences = choice.preferences();
+    for renderer_type in &preferences {
+        // PlatformData isn't Clone, every attempt builds its own
+        let pd = imgui_bgfx_renderer::platform_data(window.raw_window_handle()).ok_or(Error::UnsupportedWindowSystem)?;
+        bgfx::set_platform_data(&pd);
+        let mut init = Init::new();
+        init.type_r = *renderer_type;
+        init.resolution.width = DEFAULT_WIDTH;
+        init.resolution.height = DEFAULT_HEIGHT;
+        init.resolution.reset = ResetFlags::VSYNC.bits();
+        init.platform_data = pd;
+        match BgfxInstance::init(&init) {
+            Some(instance) => {
+                println!("renderer: using {:?}", bgfx::get_renderer_type());
+                return Ok(instance);
+            },
+            None => eprintln!("renderer: {:?} failed to initialize", renderer_type),
+        }
+    }
+    Err(Error::BgfxInit(preferences))
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct FontSettings {
+    size_pixels : f32,
+    japanese_glyphs : bool
+}
+
+impl Default for FontSettings {
+    fn default() -> Self {
+        FontSettings { size_pixels: 13.0 * 2., japanese_glyphs: true }
+    }
+}
+
+/// (Re)builds the font list of the atlas. Can be called again at runtime,
+/// followed by `Renderer::reload_font_texture` to upload the new atlas.
+///
+/// Glyphs are rasterized at the monitor's content scale, so text stays sharp, and scaled back
+/// by the framebuffer scale, as imgui lays out in window coordinates.
+fn load_fonts(imgui_context : &mut Context, settings : &FontSettings, scale : DisplayScale){
+    let io = imgui_context.io_mut();
+    io.font_global_scale = 1.0 / scale.framebuffer;
+    let mut fonts = imgui_context.fonts();
+    fonts.clear();
+    let font_size = settings.size_pixels * scale.content;
+    let mut sources = vec![imgui::FontSource::TtfData {
+        data: include_bytes!("../../Roboto-Regular.ttf"),
+        size_pixels: font_size,
+        config: Some(imgui::FontConfig {
+            // As imgui-glium-renderer isn't gamma-correct with
+            // it's font rendering, we apply an arbitrary
+            // multiplier to make the font a bit "heavier". With
+            // default imgui-glow-renderer this is unnecessary.
+            rasterizer_multiply: 1.5,
+            // Oversampling font helps improve text rendering at
+            // expense of larger font atlas texture.
+            oversample_h: 4,
+            oversample_v: 4,
+            ..imgui::FontConfig::default()
+        }),
+    }];
+    if settings.japanese_glyphs {
+        sources.push(imgui::FontSource::TtfData {
+            data: include_bytes!("../../mplus-1p-regular.ttf"),
+            size_pixels: font_size,
+            config: Some(imgui::FontConfig {
+                // Oversampling font helps improve text rendering at
+                // expense of larger font atlas texture.
+                oversample_h: 4,
+                oversample_v: 4,
+                // Range of glyphs to rasterize
+                glyph_ranges: imgui::FontGlyphRanges::japanese(),
+                ..imgui::FontConfig::default()
+            }),
+        });
+    }
+    // sources after the first one are merged into it
+    fonts.add_font(&sources);
+}
+
+fn show_font_settings(ui : &imgui::Ui, settings : &mut FontSettings) -> bool {
+    let mut changed = false;
+    imgui::Window::new("Fonts").always_auto_resize(true).build(ui, || {
+        imgui::Slider::new("Size", 8.0, 48.0).build(ui, &mut settings.size_pixels);
+        // rebuilding the atlas is slow, so only apply the size once the slider is released
+        changed |= ui.is_item_deactivated_after_edit();
+        changed |= ui.checkbox("Japanese glyphs", &mut settings.japanese_glyphs);
+    });
+    changed
+}
+
+/// Draws the widgets between two draw callbacks with additive blending.
+fn show_render_state(ui : &imgui::Ui, callbacks : &imgui_bgfx_renderer::DrawCallbacks<'_>, additive : &mut bool) {
+    imgui::Window::new("Render state").always_auto_resize(true).build(ui, || {
+        ui.checkbox("Additive blending", additive);
+        if *additive {
+            callbacks.add(ui, |context| context.state.state = StateWriteFlags::RGB.bits() | StateFlags::MSAA.bits()
+                | imgui_bgfx_renderer::blend_function(StateBlendFlags::SRC_ALPHA, StateBlendFlags::ONE));
+        }
+        ui.button("Glowing button");
+        ui.text_colored([1.0, 0.5, 0.2, 1.0], "Glowing text");
+        callbacks.add_reset(ui);
+        ui.text("Back to alpha blending");
+    });
+}
+
+fn toggle_fullscreen(glfw : &mut glfw::Glfw, window : &mut glfw::Window) -> Result<()> {
+    let mut is_fullscreen = false;
+    window.with_window_mode_mut(|mode| {
+        match mode {
+            glfw::WindowMode::Windowed => { is_fullscreen = false; },
+            glfw::WindowMode::FullScreen(_m) => { is_fullscreen = true; },
+        }
+    });
+    glfw.with_primary_monitor_mut(|_: &mut _, m: Option<&glfw::Monitor>| {
+        let monitor = m.ok_or(Error::NoPrimaryMonitor)?;
+        let mode: glfw::VidMode = monitor.get_video_mode().ok_or(Error::NoVideoMode)?;
+        match is_fullscreen {
+            false => window.set_monitor(glfw::WindowMode::FullScreen(monitor), 0, 0, mode.width, mode.height, Some(mode.refresh_rate)),
+            true => window.set_monitor(glfw::WindowMode::Windowed, 0, 0, DEFAULT_WIDTH, DEFAULT_HEIGHT, Some(mode.refresh_rate)),
+        }
+        Ok(())
+    })
+}
+
+/// `--headless [frames]` runs the UI on the Noop backend without opening a window.
+fn headless_frame_count() -> Option<u32> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--headless" {
+            return Some(args.next().and_then(|frames| frames.parse().ok()).unwrap_or(60));
+        }
+    }
+    None
+}
+
+/// Value following `name` on the command line, e.g. `--capture frames.imdc`.
+fn arg_value(name : &str) -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == name {
+            return args.next();
+        }
+    }
+    None
+}
+
+fn create_capture(path : &str) -> Result<draw_capture::CaptureWriter<std::io::BufWriter<std::fs::File>>> {
+    let file = std::fs::File::create(path).map_err(Error::io(format!("failed to create capture {}", path)))?;
+    draw_capture::CaptureWriter::new(std::io::BufWriter::new(file)).map_err(Error::io(format!("failed to write capture {}", path)))
+}
+
+/// Feeds a capture through the renderer on the Noop backend, and through the
+/// software rasterizer when `png_dir` is given to get one PNG per frame.
+fn run_replay(path : &str, png_dir : Option<String>) -> Result<()> {
+    let file = std::fs::File::open(path).map_err(Error::io(format!("failed to open capture {}", path)))?;
+    let mut reader = draw_capture::CaptureReader::new(std::io::BufReader::new(file)).map_err(Error::io(format!("failed to read capture {}", path)))?;
+    let bgfx_instance = headless::init_bgfx_headless()?;
+    let mut imgui_context = imgui::Context::create();
+    // the recording registered the same fonts first, so the atlas ends up with the same texture id
+    load_fonts(&mut imgui_context, &FontSettings::default(), DisplayScale::default());
+    let mut renderer = imgui_bgfx_renderer::Renderer::init(&bgfx_instance, &mut imgui_context)?;
+    let mut rasterizer = software_rasterizer::SoftwareRasterizer::new();
+    rasterizer.register_font_atlas(&mut imgui_context);
+    let mut frame_count = 0;
+    while let Some(frame) = reader.read_frame().map_err(Error::io(format!("corrupt capture {}", path)))? {
+        bgfx::touch(0);
+        renderer.render_captured(0xFF, &frame);
+        bgfx::frame(false);
+        if let Some(png_dir) = &png_dir {
+            let png_path = std::path::Path::new(png_dir).join(format!("frame_{:05}.png", frame_count));
+            rasterizer.rasterize_captured(&frame).save_png(&png_path).map_err(Error::io(format!("failed to write {}", png_path.display())))?;
+        }
+        frame_count += 1;
+    }
+    println!("replay: {} frames from {}", frame_count, path);
+    Ok(())
+}
+
+/// Runs `frame_count` frames, or the frames of `--play <recording>` when given.
+fn run_headless(frame_count : u32) -> Result<()> {
+    let bgfx_instance = headless::init_bgfx_headless()?;
+    let mut imgui_context = imgui::Context::create();
+    load_fonts(&mut imgui_context, &FontSettings::default(), DisplayScale::default());
+    let mut app = headless::HeadlessApp::new(&bgfx_instance, imgui_context)?;
+    let mut font_settings = FontSettings::default();
+    let mut build_ui = |ui : &imgui::Ui| {
+        let mut test: bool = true;
+        ui.show_demo_window(&mut test);
+        show_font_settings(ui, &mut font_settings);
+    };
+    match arg_value("--play") {
+        Some(path) => {
+            let mut player = InputPlayer::load(&path, headless::HEADLESS_DELTA_TIME)?;
+            app.play(&mut player, build_ui);
+        },
+        None => {
+            for _ in 0..frame_count {
+                app.frame(&[], &mut build_ui);
+            }
+        }
+    }
+    let stats = app.render_stats();
+    println!("headless: {} frames, {} draw lists in the last frame, {} fallback draw lists in total", app.frame_count(), stats.draw_lists, stats.total_fallback_draw_lists);
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let result = if let Some(path) = arg_value("--replay") {
+        run_replay(&path, arg_value("--replay-png"))
+    } else if let Some(frame_count) = headless_frame_count() {
+        run_headless(frame_count)
+    } else {
+        match backend_choice() {
+            Ok(backend_choice) => run_windowed(backend_choice),
+            Err(e) => {
+                eprintln!("error: invalid configuration: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_windowed(backend_choice : BackendChoice) -> Result<()> {
+    // errors after initialization are reported through the return values instead of aborting
+    let mut glfw = glfw::init(glfw::LOG_ERRORS)?;
+    glfw.window_hint(glfw::WindowHint::ClientApi(glfw::ClientApiHint::NoApi));
+    let (mut window, events) = glfw
+        .create_window(
+            DEFAULT_WIDTH as _,
+            DEFAULT_HEIGHT as _,
+            "App - ESC to close",
+            glfw::WindowMode::Windowed,
+        )
+        .ok_or(Error::WindowCreation)?;
+    window.set_key_polling(true);
+    window.set_scroll_polling(true);
+    window.set_char_polling(true);
+    window.set_cursor_pos_polling(true);
+    window.set_mouse_button_polling(true);
+    window.set_cursor_enter_polling(true);
+    window.set_focus_polling(true);
+    window.set_size_polling(true);
+    window.set_content_scale_polling(true);
+    let mut input_recorder = match arg_value("--record") {
+        Some(path) => {
+            let file = std::fs::File::create(&path).map_err(Error::io(format!("failed to create recording {}", path)))?;
+            Some(InputRecorder::new(std::io::BufWriter::new(file), window.get_size()).map_err(Error::io(format!("failed to write recording {}", path)))?)
+        },
+        None => None,
+    };
+
+    let bgfx_instance = init_bgfx(&window, backend_choice)?;
+    {
+        let mut imgui_context = imgui::Context::create();
+        let mut font_settings = FontSettings::default();
+        let mut display_scale = DisplayScale::of(&window);
+        load_fonts(&mut imgui_context, &font_settings, display_scale);
+        // the window outlives the imgui context, it is only dropped at the end of main
+        let clipboard = unsafe { GlfwClipboard::new(&window) };
+        let mut glfw_platform = GlfwPlatform::init(&mut imgui_context, clipboard)?;
+        imgui_context.io_mut().config_flags |= imgui::ConfigFlags::NAV_ENABLE_KEYBOARD;
+        let mut imgui_renderer = imgui_bgfx_renderer::Renderer::init(&bgfx_instance, &mut imgui_context)?;
+        let draw_callbacks = imgui_renderer.draw_callbacks();
+        let mut additive_blending = true;
+        bgfx::set_debug(DebugFlags::TEXT.bits());
+        let mut capture = arg_value("--capture").map(|path| create_capture(&path)).transpose()?;
+        // development mode, e.g. `--shader-dir shaders/bin` after recompiling with shaderc
+        if let Some(dir) = arg_value("--shader-dir") {
+            imgui_renderer.watch_shaders(dir)?;
+        }
+        let mut old_size = (0, 0);
+        {
+            while !window.should_close() {
+                glfw.poll_events();
+                for (_, event) in glfw::flush_messages(&events) {
+                    if let Some(recorder) = input_recorder.as_mut() {
+                        recorder.record(&event).map_err(Error::io("failed to write recording"))?;
+                    }
+                    glfw_platform.handle_event(&mut imgui_context, &event);
+                    if let glfw::WindowEvent::Key(key, _, action, _) = event {
+                        if action == Action::Press {
+                            if key == Key::Escape {
+                                window.set_should_close(true)
+                            }else if imgui_context.io().key_alt && key == Key::Enter {
+                                // not being able to switch is no reason to quit
+                                if let Err(e) = toggle_fullscreen(&mut glfw, &mut window) {
+                                    eprintln!("fullscreen: {}", e);
+                                }
+                            }
+                        }
+                    }
+                }
+                let size = window.get_framebuffer_size();
+                if old_size != size {
+                    //bgfx::reset(size.0 as _, size.1 as _, ResetArgs::default());
+                    bgfx::reset(size.0 as _, size.1 as _, ResetArgs{ flags: ResetFlags::VSYNC.bits(), format: TextureFormat::Count });
+                    old_size = size;
+                }
+                bgfx::set_view_clear(
+                    0,
+                    ClearFlags::COLOR.bits() | ClearFlags::DEPTH.bits(),
+                    SetViewClearArgs {
+                        rgba: 0x103030ff,
+                        ..Default::default()
+                    },
+                );
+                for view_id in 0..2 {
+                    bgfx::set_view_rect(view_id, 0, 0, size.0 as _, size.1 as _);
+                }
+                bgfx::touch(0);
+                glfw_platform.prepare_frame(imgui_context.io_mut(), &window);
+                {
+                    let mut test: bool = true;
+                    let ui = imgui_context.frame();
+                    ui.show_demo_window(&mut test);
+                    let mut fonts_changed = show_font_settings(&ui, &mut font_settings);
+                    show_render_state(&ui, &draw_callbacks, &mut additive_blending);
+                    imgui_renderer.show_shader_errors(&ui);
+                    glfw_platform.prepare_render(&ui, &mut window);
+
+                    let draw_data = ui.render();
+                    if let Some(capture) = capture.as_mut() {
+                        capture.write_draw_data(draw_data).map_err(Error::io("failed to write capture"))?;
+                    }
+                    imgui_renderer.render(0xFF, draw_data);
+                    if glfw_platform.take_display_scale_change() {
+                        display_scale = DisplayScale::of(&window);
+                        fonts_changed = true;
+                    }
+                    if fonts_changed {
+                        load_fonts(&mut imgui_context, &font_settings, display_scale);
+                        imgui_renderer.reload_font_texture(&mut imgui_context);
+                    }
+                }
+                bgfx::frame(false);
+                if let Some(recorder) = input_recorder.as_mut() {
+                    recorder.next_frame();
+                }
+            }
+        }
+    }
+    if let Some(recorder) = input_recorder {
+        recorder.finish().map_err(Error::io("failed to write recording"))?;
+    }
+    // shuts bgfx down, the renderer borrowing it is gone by now
+    drop(bgfx_instance);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bgfx_imgui_glfw_rs::test_support::lock_globals;
+
+    #[test]
+    fn rasterizes_fonts_at_the_content_scale() {
+        let _lock = lock_globals();
+        let mut imgui_context = imgui::Context::create();
+        let settings = FontSettings { size_pixels: 20.0, japanese_glyphs: false };
+        load_fonts(&mut imgui_context, &settings, DisplayScale { content: 2.0, framebuffer: 2.0 });
+        imgui_context.fonts().build_rgba32_texture();
+        assert_eq!(imgui_context.fonts().fonts().len(), 1);
+        let font_id = imgui_context.fonts().fonts()[0];
+        assert_eq!(imgui_context.fonts().get_font(font_id).unwrap().font_size, 40.0);
+        assert_eq!(imgui_context.io().font_global_scale, 0.5);
+    }
+
+    #[test]
+    fn font_settings_panel_matches_golden() {
+        let _lock = lock_globals();
+        let mut imgui_context = imgui::Context::create();
+        imgui_context.set_ini_filename(None);
+        let mut rasterizer = software_rasterizer::SoftwareRasterizer::new();
+        rasterizer.register_font_atlas(&mut imgui_context);
+        imgui_context.io_mut().display_size = [1280.0, 720.0];
+        imgui_context.io_mut().delta_time = 1.0 / 60.0;
+        let mut settings = FontSettings::default();
+        for _ in 1..3 {
+            show_font_settings(&imgui_context.frame(), &mut settings);
+        }
+        let ui = imgui_context.frame();
+        show_font_settings(&ui, &mut settings);
+        let image = rasterizer.rasterize(ui.render());
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+        let result = software_rasterizer::check_golden(root.join("tests/golden/font_settings.png"), root.join("target/font_settings.actual.png"), &image);
+        if let Err(e) = result {
+            panic!("{}", e);
+        }
+    }
+}
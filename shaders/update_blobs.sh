@@ -0,0 +1,38 @@
+#!/bin/sh
+# Recompiles the precompiled blobs in shaders/bin/ that build.rs falls back to.
+#
+#   SHADERC=/path/to/shaderc shaders/update_blobs.sh [profile...]
+#
+# Profiles default to all of glsl, essl, spirv, dx9, dx11 and metal. shaderc only has the
+# Direct3D compilers on Windows, so elsewhere pass the other profiles explicitly.
+set -eu
+
+shaderc=${SHADERC:-shaderc}
+dir=$(cd "$(dirname "$0")" && pwd)
+if [ $# -eq 0 ]; then
+    set -- glsl essl spirv dx9 dx11 metal
+fi
+
+for profile in "$@"; do
+    for name in vs_ocornut_imgui fs_ocornut_imgui; do
+        case $name in
+            vs_*) type=vertex; stage=vs ;;
+            *) type=fragment; stage=ps ;;
+        esac
+        # same arguments as build.rs
+        case $profile in
+            glsl) args="--platform linux" ;;
+            essl) args="--platform android" ;;
+            spirv) args="--platform linux -p spirv" ;;
+            dx9) args="--platform windows -p ${stage}_3_0 -O 3" ;;
+            dx11) args="--platform windows -p ${stage}_4_0 -O 3" ;;
+            metal) args="--platform ios -p metal -O 3" ;;
+            *) echo "unknown profile $profile" >&2; exit 1 ;;
+        esac
+        mkdir -p "$dir/bin/$profile"
+        # shellcheck disable=SC2086
+        "$shaderc" -f "$dir/$name.sc" -o "$dir/bin/$profile/$name.bin" --type $type \
+            -i "$dir/include" --varyingdef "$dir/varying.def.sc" $args
+        echo "$profile/$name.bin"
+    done
+done
@@ -0,0 +1,306 @@
+//! Binary captures of imgui [`DrawData`], to reproduce rendering bugs on another machine.
+//!
+//! A capture file holds a header followed by one record per frame, all little endian:
+//!
+//! | Field        | Layout                                                                 |
+//! | ------------ | ---------------------------------------------------------------------- |
+//! | header       | `b"IMDC"`, version `u16`, vertex size `u16`, index size `u8`           |
+//! | frame        | display_pos, display_size, framebuffer_scale as `f32` pairs, list count `u32` |
+//! | draw list    | vertex count `u32`, vertices, index count `u32`, indices, command count `u32` |
+//! | vertex       | pos `[f32; 2]`, uv `[f32; 2]`, col `[u8; 4]`                           |
+//! | command      | kind `u8`, for elements: count `u32`, clip_rect `[f32; 4]`, texture id `u64`, vtx_offset `u32`, idx_offset `u32` |
+//!
+//! Draw callbacks are raw function pointers into the recording process and are left out.
+//! Texture ids are stored as is, so replays only show the right images for textures that get
+//! registered in the same order, e.g. the font atlas.
+use std::io::{self, Read, Write};
+use imgui::{DrawCmd, DrawCmdParams, DrawData, DrawIdx, DrawVert, TextureId};
+use crate::imgui_bgfx_renderer::draw_lists;
+
+const MAGIC: &[u8; 4] = b"IMDC";
+const VERSION: u16 = 1;
+
+const COMMAND_ELEMENTS: u8 = 0;
+const COMMAND_RESET_RENDER_STATE: u8 = 1;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CapturedCommand {
+    Elements { count : usize, cmd_params : DrawCmdParams },
+    ResetRenderState
+}
+
+impl CapturedCommand {
+    pub fn to_draw_cmd(&self) -> DrawCmd {
+        match self {
+            CapturedCommand::Elements { count, cmd_params } => DrawCmd::Elements { count: *count, cmd_params: *cmd_params },
+            CapturedCommand::ResetRenderState => DrawCmd::ResetRenderState,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CapturedDrawList {
+    pub vertices : Vec<DrawVert>,
+    pub indices : Vec<DrawIdx>,
+    pub commands : Vec<CapturedCommand>
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CapturedFrame {
+    pub display_pos : [f32; 2],
+    pub display_size : [f32; 2],
+    pub framebuffer_scale : [f32; 2],
+    pub draw_lists : Vec<CapturedDrawList>
+}
+
+impl CapturedFrame {
+    pub fn from_draw_data(draw_data : &DrawData) -> CapturedFrame {
+        let draw_lists = draw_lists(draw_data).map(|draw_list| {
+            let commands = draw_list.commands().filter_map(|command| match command {
+                DrawCmd::Elements { count, cmd_params } => Some(CapturedCommand::Elements { count, cmd_params }),
+                DrawCmd::ResetRenderState => Some(CapturedCommand::ResetRenderState),
+                DrawCmd::RawCallback { .. } => None,
+            }).collect();
+            CapturedDrawList { vertices: draw_list.vtx_buffer().to_vec(), indices: draw_list.idx_buffer().to_vec(), commands }
+        }).collect();
+        CapturedFrame {
+            display_pos: draw_data.display_pos,
+            display_size: draw_data.display_size,
+            framebuffer_scale: draw_data.framebuffer_scale,
+            draw_lists
+        }
+    }
+}
+
+/// Indices drawn by an elements command, `None` unless they lie within `indices` and every one
+/// of them, shifted by `vtx_offset`, points at one of the `vertex_count` vertices.
+pub(crate) fn command_indices(indices : &[DrawIdx], vertex_count : usize, count : usize, vtx_offset : usize, idx_offset : usize) -> Option<&[DrawIdx]> {
+    let command_indices = indices.get(idx_offset..idx_offset.checked_add(count)?)?;
+    let in_bounds = command_indices.iter().all(|&index| vtx_offset.checked_add(index as usize).is_some_and(|vertex| vertex < vertex_count));
+    in_bounds.then_some(command_indices)
+}
+
+fn invalid_data(message : &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+pub struct CaptureWriter<W : Write> {
+    writer : W
+}
+
+impl<W : Write> CaptureWriter<W> {
+    pub fn new(mut writer : W) -> io::Result<Self> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(std::mem::size_of::<DrawVert>() as u16).to_le_bytes())?;
+        writer.write_all(&[std::mem::size_of::<DrawIdx>() as u8])?;
+        Ok(CaptureWriter { writer })
+    }
+    pub fn write_draw_data(&mut self, draw_data : &DrawData) -> io::Result<()> {
+        self.write_frame(&CapturedFrame::from_draw_data(draw_data))
+    }
+    pub fn write_frame(&mut self, frame : &CapturedFrame) -> io::Result<()> {
+        let mut buffer = Vec::new();
+        for value in frame.display_pos.iter().chain(&frame.display_size).chain(&frame.framebuffer_scale) {
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        buffer.extend_from_slice(&(frame.draw_lists.len() as u32).to_le_bytes());
+        for draw_list in &frame.draw_lists {
+            buffer.extend_from_slice(&(draw_list.vertices.len() as u32).to_le_bytes());
+            for vertex in &draw_list.vertices {
+                for value in vertex.pos.iter().chain(&vertex.uv) {
+                    buffer.extend_from_slice(&value.to_le_bytes());
+                }
+                buffer.extend_from_slice(&vertex.col);
+            }
+            buffer.extend_from_slice(&(draw_list.indices.len() as u32).to_le_bytes());
+            for index in &draw_list.indices {
+                buffer.extend_from_slice(&index.to_le_bytes());
+            }
+            buffer.extend_from_slice(&(draw_list.commands.len() as u32).to_le_bytes());
+            for command in &draw_list.commands {
+                match command {
+                    CapturedCommand::Elements { count, cmd_params } => {
+                        buffer.push(COMMAND_ELEMENTS);
+                        buffer.extend_from_slice(&(*count as u32).to_le_bytes());
+                        for value in &cmd_params.clip_rect {
+                            buffer.extend_from_slice(&value.to_le_bytes());
+                        }
+                        buffer.extend_from_slice(&(cmd_params.texture_id.id() as u64).to_le_bytes());
+                        buffer.extend_from_slice(&(cmd_params.vtx_offset as u32).to_le_bytes());
+                        buffer.extend_from_slice(&(cmd_params.idx_offset as u32).to_le_bytes());
+                    },
+                    CapturedCommand::ResetRenderState => buffer.push(COMMAND_RESET_RENDER_STATE),
+                }
+            }
+        }
+        self.writer.write_all(&buffer)
+    }
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+pub struct CaptureReader<R : Read> {
+    reader : R
+}
+
+impl<R : Read> CaptureReader<R> {
+    /// Checks the header, captures recorded with a different vertex or index layout are rejected.
+    pub fn new(mut reader : R) -> io::Result<Self> {
+        let mut header = [0u8; 9];
+        reader.read_exact(&mut header)?;
+        if &header[0..4] != MAGIC {
+            return Err(invalid_data("not a draw data capture"));
+        }
+        if u16::from_le_bytes([header[4], header[5]]) != VERSION {
+            return Err(invalid_data("unsupported capture version"));
+        }
+        if u16::from_le_bytes([header[6], header[7]]) as usize != std::mem::size_of::<DrawVert>() || header[8] as usize != std::mem::size_of::<DrawIdx>() {
+            return Err(invalid_data("capture was recorded with a different vertex or index layout"));
+        }
+        Ok(CaptureReader { reader })
+    }
+    /// Reads the next frame, `None` once the capture is exhausted.
+    pub fn read_frame(&mut self) -> io::Result<Option<CapturedFrame>> {
+        let mut first = [0u8; 1];
+        if self.reader.read(&mut first)? == 0 {
+            return Ok(None);
+        }
+        let mut rest = [0u8; 3];
+        self.reader.read_exact(&mut rest)?;
+        let display_pos = [f32::from_le_bytes([first[0], rest[0], rest[1], rest[2]]), self.read_f32()?];
+        let display_size = [self.read_f32()?, self.read_f32()?];
+        let framebuffer_scale = [self.read_f32()?, self.read_f32()?];
+        let list_count = self.read_u32()?;
+        let mut draw_lists = Vec::new();
+        for _ in 0..list_count {
+            let vertex_count = self.read_u32()?;
+            let mut vertices = Vec::with_capacity(vertex_count.min(1 << 20) as usize);
+            for _ in 0..vertex_count {
+                let pos = [self.read_f32()?, self.read_f32()?];
+                let uv = [self.read_f32()?, self.read_f32()?];
+                let mut col = [0u8; 4];
+                self.reader.read_exact(&mut col)?;
+                vertices.push(DrawVert { pos, uv, col });
+            }
+            let index_count = self.read_u32()?;
+            let mut indices = Vec::with_capacity(index_count.min(1 << 20) as usize);
+            for _ in 0..index_count {
+                let mut bytes = [0u8; std::mem::size_of::<DrawIdx>()];
+                self.reader.read_exact(&mut bytes)?;
+                indices.push(DrawIdx::from_le_bytes(bytes));
+            }
+            let command_count = self.read_u32()?;
+            let mut commands = Vec::new();
+            for _ in 0..command_count {
+                let mut kind = [0u8; 1];
+                self.reader.read_exact(&mut kind)?;
+                let command = match kind[0] {
+                    COMMAND_ELEMENTS => {
+                        let count = self.read_u32()? as usize;
+                        let clip_rect = [self.read_f32()?, self.read_f32()?, self.read_f32()?, self.read_f32()?];
+                        let texture_id = TextureId::new(self.read_u64()? as usize);
+                        let vtx_offset = self.read_u32()? as usize;
+                        let idx_offset = self.read_u32()? as usize;
+                        if command_indices(&indices, vertices.len(), count, vtx_offset, idx_offset).is_none() {
+                            return Err(invalid_data("draw command out of the draw list's bounds"));
+                        }
+                        CapturedCommand::Elements { count, cmd_params: DrawCmdParams { clip_rect, texture_id, vtx_offset, idx_offset } }
+                    },
+                    COMMAND_RESET_RENDER_STATE => CapturedCommand::ResetRenderState,
+                    _ => return Err(invalid_data("unknown draw command")),
+                };
+                commands.push(command);
+            }
+            draw_lists.push(CapturedDrawList { vertices, indices, commands });
+        }
+        Ok(Some(CapturedFrame { display_pos, display_size, framebuffer_scale, draw_lists }))
+    }
+    fn read_u32(&mut self) -> io::Result<u32> {
+        let mut bytes = [0u8; 4];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
+    }
+    fn read_u64(&mut self) -> io::Result<u64> {
+        let mut bytes = [0u8; 8];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+    fn read_f32(&mut self) -> io::Result<f32> {
+        Ok(f32::from_bits(self.read_u32()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "software-rasterizer")]
+    fn captures_round_trip_and_replay_identically() {
+        use crate::software_rasterizer::SoftwareRasterizer;
+        use crate::test_support::lock_globals;
+        let _lock = lock_globals();
+        let mut imgui_context = imgui::Context::create();
+        imgui_context.set_ini_filename(None);
+        let mut rasterizer = SoftwareRasterizer::new();
+        rasterizer.register_font_atlas(&mut imgui_context);
+        imgui_context.io_mut().display_size = [800.0, 600.0];
+        imgui_context.io_mut().delta_time = 1.0 / 60.0;
+        let mut writer = CaptureWriter::new(Vec::new()).unwrap();
+        let mut expected = Vec::new();
+        let mut open = true;
+        for _ in 0..3 {
+            let ui = imgui_context.frame();
+            ui.show_demo_window(&mut open);
+            let draw_data = ui.render();
+            writer.write_draw_data(draw_data).unwrap();
+            expected.push(rasterizer.rasterize(draw_data));
+        }
+
+        let capture = writer.into_inner();
+        let mut reader = CaptureReader::new(capture.as_slice()).unwrap();
+        let mut replayed = Vec::new();
+        while let Some(frame) = reader.read_frame().unwrap() {
+            replayed.push(rasterizer.rasterize_captured(&frame));
+        }
+        assert_eq!(replayed, expected);
+    }
+
+    #[test]
+    fn rejects_foreign_and_truncated_data() {
+        assert!(CaptureReader::new(&b"PNG\0\0\0\0\0\0"[..]).is_err());
+
+        let mut frame = CapturedFrame { display_size: [64.0, 64.0], framebuffer_scale: [1.0, 1.0], ..Default::default() };
+        frame.draw_lists.push(CapturedDrawList {
+            vertices: vec![DrawVert { pos: [0.0, 0.0], uv: [0.0, 0.0], col: [255; 4] }; 3],
+            indices: vec![0, 1, 2],
+            commands: vec![CapturedCommand::Elements {
+                count: 3,
+                cmd_params: DrawCmdParams { clip_rect: [0.0, 0.0, 64.0, 64.0], texture_id: TextureId::new(7), vtx_offset: 0, idx_offset: 0 }
+            }]
+        });
+        let mut writer = CaptureWriter::new(Vec::new()).unwrap();
+        writer.write_frame(&frame).unwrap();
+        let capture = writer.into_inner();
+
+        let mut reader = CaptureReader::new(capture.as_slice()).unwrap();
+        assert_eq!(reader.read_frame().unwrap(), Some(frame.clone()));
+        assert_eq!(reader.read_frame().unwrap(), None);
+
+        let mut reader = CaptureReader::new(&capture[..capture.len() - 1]).unwrap();
+        assert!(reader.read_frame().is_err());
+
+        // an index past the last vertex
+        frame.draw_lists[0].indices[2] = 3;
+        let mut writer = CaptureWriter::new(Vec::new()).unwrap();
+        writer.write_frame(&frame).unwrap();
+        let capture = writer.into_inner();
+        let mut reader = CaptureReader::new(capture.as_slice()).unwrap();
+        assert_eq!(reader.read_frame().unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,82 @@
+//! Errors of the library's fallible operations.
+//!
+//! `Renderer::init`, `GlfwPlatform::init`, `Renderer::watch_shaders`, `init_bgfx_headless` and
+//! `HeadlessApp::new` return these instead of panicking, so an application can report them and
+//! exit cleanly. [`Error::Recording`] and [`Error::Io`] cover input recordings and captures,
+//! so applications can report everything through one type. New variants may be added, so
+//! matches need a catch-all arm.
+use std::fmt;
+use std::io;
+use bgfx_rs::bgfx::RendererType;
+use crate::imgui_glfw_support::recording::ParseError;
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    GlfwInit(glfw::InitError),
+    WindowCreation,
+    NoPrimaryMonitor,
+    NoVideoMode,
+    /// The window system of the native window handle isn't one bgfx can render into.
+    UnsupportedWindowSystem,
+    /// There are no imgui shaders compiled for this backend.
+    UnsupportedRenderer(RendererType),
+    /// A shader blob that is malformed or doesn't fit the renderer, see `shader_binary`.
+    InvalidShader(String),
+    /// None of the backends tried initialized, in the order they were tried.
+    BgfxInit(Vec<RendererType>),
+    /// The imgui context already has a platform backend, named by the value.
+    PlatformAlreadyInitialized(String),
+    /// The imgui context already has a renderer backend, named by the value.
+    RendererAlreadyInitialized(String),
+    /// An input recording that can't be parsed.
+    Recording(ParseError),
+    /// A file operation, `context` names the operation and the path.
+    Io { context : String, source : io::Error }
+}
+
+impl Error {
+    /// Wraps an `io::Error`, e.g. `Error::io(format!("failed to open capture {}", path))`.
+    pub fn io(context : impl Into<String>) -> impl FnOnce(io::Error) -> Error {
+        let context = context.into();
+        move |source| Error::Io { context, source }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::GlfwInit(e) => write!(f, "failed to initialize GLFW: {}", e),
+            Error::WindowCreation => write!(f, "failed to create GLFW window"),
+            Error::NoPrimaryMonitor => write!(f, "no primary monitor connected"),
+            Error::NoVideoMode => write!(f, "the primary monitor reports no video mode"),
+            Error::UnsupportedWindowSystem => write!(f, "unsupported window manager"),
+            Error::UnsupportedRenderer(renderer_type) => write!(f, "no imgui shaders for renderer type {:?}", renderer_type),
+            Error::InvalidShader(message) => write!(f, "invalid imgui shader: {}", message),
+            Error::BgfxInit(tried) => write!(f, "failed to initialize bgfx with any of {:?}", tried),
+            Error::PlatformAlreadyInitialized(name) => write!(f, "the imgui context already has the platform backend {}", name),
+            Error::RendererAlreadyInitialized(name) => write!(f, "the imgui context already has the renderer backend {}", name),
+            Error::Recording(e) => write!(f, "invalid input recording: {}", e),
+            Error::Io { context, source } => write!(f, "{}: {}", context, source),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::GlfwInit(e) => Some(e),
+            Error::Recording(e) => Some(e),
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<glfw::InitError> for Error {
+    fn from(e : glfw::InitError) -> Self {
+        Error::GlfwInit(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
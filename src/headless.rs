@@ -0,0 +1,249 @@
+//! Runs the UI without a window or GPU, e.g. on CI machines.
+//!
+//! bgfx is initialized with the Noop backend and no native window handle, input is fed
+//! as synthetic GLFW events through [`GlfwPlatform`], so UI logic and the renderer
+//! bookkeeping still run exactly like in the windowed loop.
+use bgfx::*;
+use bgfx_rs::bgfx;
+use crate::error::{Error, Result};
+use crate::imgui_bgfx_renderer::{BgfxInstance, RenderStats, Renderer};
+use crate::imgui_glfw_support::GlfwPlatform;
+use crate::imgui_glfw_support::clipboard::MemoryClipboard;
+use crate::imgui_glfw_support::recording::InputPlayer;
+
+pub const HEADLESS_WIDTH: u32 = 1280;
+pub const HEADLESS_HEIGHT: u32 = 720;
+/// Frames advance by a fixed time step so runs are reproducible.
+pub const HEADLESS_DELTA_TIME: f32 = 1.0 / 60.0;
+
+pub fn init_bgfx_headless() -> Result<BgfxInstance> {
+    init_bgfx_headless_with(|_| {})
+}
+
+/// Same as [`init_bgfx_headless`], `configure` can change e.g. `init.limits` before bgfx starts.
+pub fn init_bgfx_headless_with<F : FnOnce(&mut Init)>(configure : F) -> Result<BgfxInstance> {
+    let mut init = Init::new();
+    init.type_r = RendererType::Noop;
+    init.resolution.width = HEADLESS_WIDTH;
+    init.resolution.height = HEADLESS_HEIGHT;
+    init.resolution.reset = ResetFlags::NONE.bits();
+    // zeroed platform data, there is no window to render into
+    init.platform_data = PlatformData::new();
+    configure(&mut init);
+    BgfxInstance::init(&init).ok_or_else(|| Error::BgfxInit(vec![RendererType::Noop]))
+}
+
+pub struct HeadlessApp<'bgfx> {
+    imgui_context : imgui::Context,
+    platform : GlfwPlatform,
+    clipboard : MemoryClipboard,
+    renderer : Renderer<'bgfx>,
+    /// In window coordinates, changed by `WindowEvent::Size`.
+    display_size : [f32; 2],
+    delta_time : f32,
+    frame_count : u32
+}
+
+impl<'bgfx> HeadlessApp<'bgfx> {
+    pub fn new(bgfx : &'bgfx BgfxInstance, mut imgui_context : imgui::Context) -> Result<Self> {
+        let clipboard = MemoryClipboard::new();
+        let platform = GlfwPlatform::init(&mut imgui_context, clipboard.clone())?;
+        let renderer = Renderer::init(bgfx, &mut imgui_context)?;
+        Ok(HeadlessApp { imgui_context, platform, clipboard, renderer, display_size: [HEADLESS_WIDTH as f32, HEADLESS_HEIGHT as f32], delta_time: HEADLESS_DELTA_TIME, frame_count: 0 })
+    }
+    /// Runs one frame: applies `events`, builds the UI with `build_ui` and submits it to bgfx.
+    pub fn frame<F : FnOnce(&imgui::Ui)>(&mut self, events : &[glfw::WindowEvent], build_ui : F) {
+        for event in events {
+            if let glfw::WindowEvent::Size(width, height) = event {
+                self.display_size = [*width as f32, *height as f32];
+            }
+            self.platform.handle_event(&mut self.imgui_context, event);
+        }
+        self.platform.prepare_frame_with(self.imgui_context.io_mut(), self.display_size, 1.0, self.delta_time);
+        bgfx::set_view_rect(0, 0, 0, self.display_size[0] as _, self.display_size[1] as _);
+        bgfx::touch(0);
+        let ui = self.imgui_context.frame();
+        build_ui(&ui);
+        self.renderer.render(0xFF, ui.render());
+        bgfx::frame(false);
+        self.frame_count += 1;
+    }
+    /// Runs one frame per recorded frame, using the recording's time step. The recorded window
+    /// size is part of the first frame's events.
+    pub fn play<F : FnMut(&imgui::Ui)>(&mut self, player : &mut InputPlayer, mut build_ui : F) {
+        self.delta_time = player.delta_time();
+        while let Some(events) = player.next_frame() {
+            self.frame(events, &mut build_ui);
+        }
+    }
+    pub fn frame_count(&self) -> u32 {
+        self.frame_count
+    }
+    pub fn render_stats(&self) -> RenderStats {
+        self.renderer.stats()
+    }
+    pub fn renderer(&mut self) -> &mut Renderer<'bgfx> {
+        &mut self.renderer
+    }
+    /// Stands in for the system clipboard, shared with imgui.
+    pub fn clipboard(&self) -> &MemoryClipboard {
+        &self.clipboard
+    }
+    pub fn imgui_context(&mut self) -> &mut imgui::Context {
+        &mut self.imgui_context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{fixed_window, lock_globals, with_headless_app};
+
+    #[test]
+    fn refuses_a_second_renderer() {
+        let _lock = lock_globals();
+        let bgfx_instance = init_bgfx_headless().unwrap();
+        let mut imgui_context = imgui::Context::create();
+        let _renderer = Renderer::init(&bgfx_instance, &mut imgui_context).unwrap();
+        assert!(matches!(Renderer::init(&bgfx_instance, &mut imgui_context), Err(Error::RendererAlreadyInitialized(_))));
+    }
+
+    #[test]
+    fn runs_demo_window_headless() {
+        with_headless_app(|app| {
+            let click = [
+                glfw::WindowEvent::CursorPos(40.0, 40.0),
+                glfw::WindowEvent::MouseButton(glfw::MouseButtonLeft, glfw::Action::Press, glfw::Modifiers::empty()),
+            ];
+            let mut demo_open = true;
+            for frame in 0..10 {
+                let events: &[glfw::WindowEvent] = if frame == 5 { &click } else { &[] };
+                app.frame(events, |ui| ui.show_demo_window(&mut demo_open));
+            }
+            assert_eq!(app.frame_count(), 10);
+            assert!(app.imgui_context().io().mouse_down[0]);
+            let stats = app.render_stats();
+            assert!(stats.draw_lists > 0);
+            assert_eq!(stats.fallback_draw_lists, 0);
+        });
+    }
+
+    #[test]
+    fn plays_back_recorded_input() {
+        let recording = "\
+            # toggles the checkbox, then types into the text field
+            0 0.000 size 640 480
+            1 0.016 cursor 20 40
+            2 0.033 button 0 press 0
+            3 0.050 button 0 release 0
+            5 0.083 cursor 60 64
+            6 0.100 button 0 press 0
+            7 0.116 button 0 release 0
+            9 0.150 char 104
+            10 0.166 char 105
+            14 0.233 end
+        ";
+        let mut player = InputPlayer::parse(recording, 1.0 / 30.0).unwrap();
+        with_headless_app(|app| {
+            let mut checked = false;
+            let mut text = String::new();
+            app.play(&mut player, |ui| {
+                fixed_window("input").build(ui, || {
+                    ui.checkbox("checked", &mut checked);
+                    ui.input_text("text", &mut text).build();
+                });
+            });
+            assert_eq!(app.frame_count(), 14);
+            assert_eq!(app.imgui_context().io().delta_time, 1.0 / 30.0);
+            assert_eq!(app.imgui_context().io().display_size, [640.0, 480.0]);
+            assert!(checked);
+            assert_eq!(text, "hi");
+        });
+    }
+
+    #[test]
+    fn keeps_the_last_good_shaders_until_the_files_are_fixed() {
+        with_headless_app(|app| {
+            let dir = std::env::temp_dir().join(format!("imgui_headless_shaders_{}", std::process::id()));
+            // the Noop backend reads the GLSL profile
+            let glsl = dir.join("glsl");
+            std::fs::create_dir_all(&glsl).unwrap();
+            std::fs::write(glsl.join("vs_ocornut_imgui.bin"), crate::imgui_bgfx_renderer::FS_OCORNUT_IMGUI.glsl).unwrap();
+            std::fs::write(glsl.join("fs_ocornut_imgui.bin"), crate::imgui_bgfx_renderer::FS_OCORNUT_IMGUI.glsl).unwrap();
+            app.renderer().watch_shaders(&dir).unwrap();
+            let mut demo_open = true;
+            app.frame(&[], |ui| ui.show_demo_window(&mut demo_open));
+            assert_eq!(app.renderer().shader_error(), Some("expected a vertex shader, got a Fragment shader"));
+            // still drawing with the embedded program
+            app.frame(&[], |ui| ui.show_demo_window(&mut demo_open));
+            assert!(app.render_stats().draw_lists > 0);
+
+            std::fs::write(glsl.join("vs_ocornut_imgui.bin"), crate::imgui_bgfx_renderer::VS_OCORNUT_IMGUI.glsl).unwrap();
+            app.frame(&[], |ui| ui.show_demo_window(&mut demo_open));
+            assert_eq!(app.renderer().shader_error(), None);
+            std::fs::remove_dir_all(&dir).unwrap();
+        });
+    }
+
+    #[test]
+    fn runs_draw_callbacks_in_order_with_the_state_they_left() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use crate::imgui_bgfx_renderer::{blend_function, DEFAULT_STATE};
+        with_headless_app(|app| {
+            let callbacks = app.renderer().draw_callbacks();
+            let additive = blend_function(StateBlendFlags::SRC_ALPHA, StateBlendFlags::ONE);
+            let seen = Rc::new(RefCell::new(Vec::new()));
+            let record = |seen : &Rc<RefCell<Vec<(u64, bool)>>>| {
+                let seen = seen.clone();
+                move |context : &mut crate::imgui_bgfx_renderer::DrawCallbackContext| seen.borrow_mut().push((context.state.state, context.scissor.is_some()))
+            };
+            app.frame(&[], |ui| {
+                fixed_window("callbacks").build(ui, || {
+                    let first = record(&seen);
+                    callbacks.add(ui, move |context| {
+                        first(context);
+                        context.state.state = additive;
+                    });
+                    ui.text("glowing");
+                    callbacks.add(ui, record(&seen));
+                    callbacks.add_reset(ui);
+                    callbacks.add(ui, record(&seen));
+                });
+            });
+            assert_eq!(*seen.borrow(), [(DEFAULT_STATE, true), (additive, true), (DEFAULT_STATE, true)]);
+            assert_eq!(callbacks.pending(), 0);
+
+            // callbacks only run in the frame they were added to
+            app.frame(&[], |_| {});
+            assert_eq!(seen.borrow().len(), 3);
+        });
+    }
+
+    #[test]
+    fn draw_callbacks_borrow_handles_that_outlive_the_renderer() {
+        use crate::imgui_bgfx_renderer::{FS_OCORNUT_IMGUI, VS_OCORNUT_IMGUI};
+        let _lock = lock_globals();
+        let bgfx_instance = init_bgfx_headless().unwrap();
+        let vertex = create_shader(&Memory::copy(VS_OCORNUT_IMGUI.glsl));
+        let fragment = create_shader(&Memory::copy(FS_OCORNUT_IMGUI.glsl));
+        let program = create_program(&vertex, &fragment, false);
+        let tint = Uniform::create("u_tint", UniformType::Vec4, 1);
+        let uniform_count = std::cell::Cell::new(0);
+        let mut app = HeadlessApp::new(&bgfx_instance, imgui::Context::create()).unwrap();
+        let callbacks = app.renderer().draw_callbacks();
+        app.frame(&[], |ui| {
+            fixed_window("tinted").build(ui, || {
+                callbacks.add(ui, |context| {
+                    context.state.program = Some(&program);
+                    context.state.set_uniform(&tint, [1.0, 0.5, 0.5, 1.0]);
+                    context.state.set_uniform(&tint, [0.5, 0.5, 1.0, 1.0]);
+                    uniform_count.set(context.state.uniforms.len());
+                });
+                ui.text("tinted");
+            });
+        });
+        assert_eq!(uniform_count.get(), 1);
+        assert_eq!(callbacks.pending(), 0);
+    }
+}
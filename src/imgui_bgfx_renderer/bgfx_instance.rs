@@ -0,0 +1,35 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use bgfx_rs::bgfx;
+
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// Owns the global bgfx state: `bgfx::init` on creation, `bgfx::shutdown` on drop.
+///
+/// Everything that holds bgfx handles (e.g. [`super::Renderer`]) borrows the instance,
+/// so shutting bgfx down while those handles are alive does not compile.
+pub struct BgfxInstance {
+    // bgfx has to be shut down from the thread that initialized it
+    _not_send: PhantomData<*const ()>,
+}
+
+impl BgfxInstance {
+    /// Returns `None` if bgfx failed to initialize or is already owned by another instance.
+    pub fn init(init: &bgfx::Init) -> Option<BgfxInstance> {
+        if INITIALIZED.swap(true, Ordering::SeqCst) {
+            return None;
+        }
+        if !bgfx::init(init) {
+            INITIALIZED.store(false, Ordering::SeqCst);
+            return None;
+        }
+        Some(BgfxInstance { _not_send: PhantomData })
+    }
+}
+
+impl Drop for BgfxInstance {
+    fn drop(&mut self) {
+        bgfx::shutdown();
+        INITIALIZED.store(false, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,114 @@
+//! Rust closures in imgui draw lists that change how [`super::Renderer`] draws the commands after them.
+//!
+//! imgui only keeps a C function pointer and a `void*` per callback, so [`DrawCallbacks::add`]
+//! records a no-op marker function with a unique id and the closure stays on the Rust side until
+//! the renderer reaches its command. Ids the renderer doesn't know, e.g. ones added through
+//! another renderer's handle, are skipped.
+//!
+//! Callbacks and the programs and uniforms they hand to [`DrawState`] are borrowed for the
+//! renderer's `'bgfx` lifetime, so they have to outlive the renderer.
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use bgfx_rs::bgfx;
+use bgfx::{StateBlendFlags, StateFlags, StateWriteFlags};
+use imgui::sys;
+
+/// `BGFX_STATE_BLEND_FUNC`: the same source and destination factors for color and alpha.
+pub const fn blend_function(src : StateBlendFlags, dst : StateBlendFlags) -> u64 {
+    src.bits() | (dst.bits() << 4) | (src.bits() << 8) | (dst.bits() << 12)
+}
+
+/// Alpha blending without depth test, what imgui expects.
+pub const DEFAULT_STATE: u64 = StateWriteFlags::RGB.bits() | StateWriteFlags::A.bits() | StateFlags::MSAA.bits()
+    | blend_function(StateBlendFlags::SRC_ALPHA, StateBlendFlags::INV_SRC_ALPHA);
+
+/// How the renderer draws the elements of a draw list. Every list starts with the defaults,
+/// callbacks change it for the commands after them and `DrawCmd::ResetRenderState` restores it.
+#[derive(Clone)]
+pub struct DrawState<'bgfx> {
+    /// Replaces the imgui program, it has to take the imgui vertex layout and sample `s_tex`.
+    pub program : Option<&'bgfx bgfx::Program>,
+    /// bgfx `STATE_*` bits.
+    pub state : u64,
+    /// Blend factor of `StateBlendFlags::FACTOR`, passed to `set_state` along with `state`.
+    pub blend_factor : u32,
+    /// Set before every draw, e.g. for parameters of a custom program.
+    pub uniforms : Vec<(&'bgfx bgfx::Uniform, [f32; 4])>
+}
+
+impl Default for DrawState<'_> {
+    fn default() -> Self {
+        DrawState { program: None, state: DEFAULT_STATE, blend_factor: 0, uniforms: Vec::new() }
+    }
+}
+
+impl<'bgfx> DrawState<'bgfx> {
+    /// Sets a vec4 uniform, replacing an earlier value of the same uniform.
+    pub fn set_uniform(&mut self, uniform : &'bgfx bgfx::Uniform, value : [f32; 4]) {
+        match self.uniforms.iter_mut().find(|(set, _)| std::ptr::eq(*set, uniform)) {
+            Some((_, old_value)) => *old_value = value,
+            None => self.uniforms.push((uniform, value)),
+        }
+    }
+}
+
+/// What a draw callback gets to work with.
+pub struct DrawCallbackContext<'a, 'bgfx> {
+    /// The encoder of the draw list, own draws `submit` through it to `view_id`. Anything set
+    /// on it has to be submitted before returning, or it leaks into the next imgui draw.
+    pub encoder : &'a bgfx::Encoder,
+    pub view_id : bgfx::ViewId,
+    /// Clip rect of the callback command in framebuffer pixels, `None` if it is off screen.
+    pub scissor : Option<[u16; 4]>,
+    /// Applies to the rest of the draw list.
+    pub state : &'a mut DrawState<'bgfx>
+}
+
+/// What imgui stores as a draw list callback.
+type RawCallback = unsafe extern "C" fn(*const sys::ImDrawList, *const sys::ImDrawCmd);
+type Callback<'bgfx> = Box<dyn FnOnce(&mut DrawCallbackContext<'_, 'bgfx>) + 'bgfx>;
+
+/// Unique across all handles, so a renderer never runs another renderer's callback.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Identifies our callbacks, renderers that don't know it run it as a no-op.
+unsafe extern "C" fn marker(_parent_list : *const sys::ImDrawList, _cmd : *const sys::ImDrawCmd) {}
+
+/// Handle to the callbacks of a [`super::Renderer`], see `Renderer::draw_callbacks`.
+/// Clones share the same callbacks, so it can be moved into the UI code.
+#[derive(Clone, Default)]
+pub struct DrawCallbacks<'bgfx> {
+    pending : Rc<RefCell<Vec<(usize, Callback<'bgfx>)>>>
+}
+
+impl<'bgfx> DrawCallbacks<'bgfx> {
+    /// Runs `callback` when the renderer reaches the current position of the current window's draw list.
+    pub fn add<F : FnOnce(&mut DrawCallbackContext<'_, 'bgfx>) + 'bgfx>(&self, _ui : &imgui::Ui, callback : F) {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        self.pending.borrow_mut().push((id, Box::new(callback)));
+        unsafe { sys::ImDrawList_AddCallback(sys::igGetWindowDrawList(), Some(marker), id as *mut std::os::raw::c_void); }
+    }
+    /// Puts back the renderer's defaults for the rest of the current window's draw list.
+    pub fn add_reset(&self, ui : &imgui::Ui) {
+        self.add(ui, |context| *context.state = DrawState::default());
+    }
+    /// The closure of a callback command, `None` if it isn't one of ours.
+    pub(super) fn take(&self, callback : RawCallback, raw_cmd : *const sys::ImDrawCmd) -> Option<Callback<'bgfx>> {
+        if callback as usize != marker as RawCallback as usize {
+            return None;
+        }
+        let id = unsafe { (*raw_cmd).UserCallbackData } as usize;
+        let mut pending = self.pending.borrow_mut();
+        let index = pending.iter().position(|(pending_id, _)| *pending_id == id)?;
+        Some(pending.remove(index).1)
+    }
+    /// Drops callbacks that weren't reached, e.g. in windows imgui didn't render.
+    pub(super) fn clear(&self) {
+        self.pending.borrow_mut().clear();
+    }
+    /// Callbacks added since the last `Renderer::render`.
+    pub fn pending(&self) -> usize {
+        self.pending.borrow().len()
+    }
+}
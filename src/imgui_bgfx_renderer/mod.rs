@@ -3,63 +3,154 @@
 //! This is heavily influenced by the
 //! [example from upstream](https://github.com/bkaradzic/bgfx/blob/master/examples/common/imgui/imgui.cpp).
 //!
-use std::time::Instant;
+use std::marker::PhantomData;
 use imgui::{internal::RawWrapper, DrawData};
 use bgfx_rs::bgfx;
 use bgfx::*;
+use crate::draw_capture::CapturedFrame;
+use crate::error::{Error, Result};
 
+mod bgfx_instance;
+mod draw_callback;
+mod platform_data;
+mod shader_set;
+pub mod shader_binary;
+pub mod shader_reload;
 mod vs_ocornut_imgui;
 mod fs_ocornut_imgui;
 
-use vs_ocornut_imgui::*;
-use fs_ocornut_imgui::*;
+pub use bgfx_instance::BgfxInstance;
+pub use draw_callback::{blend_function, DrawCallbackContext, DrawCallbacks, DrawState, DEFAULT_STATE};
+pub use platform_data::platform_data;
+pub use shader_set::ShaderSet;
+use shader_set::shader_set;
+
+/// Vertex shader of the upstream imgui example.
+pub const VS_OCORNUT_IMGUI: ShaderSet = shader_set!(vs_ocornut_imgui::{
+    VS_OCORNUT_IMGUI_GLSL, VS_OCORNUT_IMGUI_ESSL, VS_OCORNUT_IMGUI_SPV, VS_OCORNUT_IMGUI_DX9, VS_OCORNUT_IMGUI_DX11, VS_OCORNUT_IMGUI_MTL
+});
+/// Fragment shader of the upstream imgui example.
+pub const FS_OCORNUT_IMGUI: ShaderSet = shader_set!(fs_ocornut_imgui::{
+    FS_OCORNUT_IMGUI_GLSL, FS_OCORNUT_IMGUI_ESSL, FS_OCORNUT_IMGUI_SPV, FS_OCORNUT_IMGUI_DX9, FS_OCORNUT_IMGUI_DX11, FS_OCORNUT_IMGUI_MTL
+});
 
-macro_rules! get_shader_code {
-    ($name:ident) => {
-        match bgfx::get_renderer_type() {
-            RendererType::Direct3D9 => &concat_idents!($name, _DX9),
-            RendererType::Direct3D11 => &concat_idents!($name, _DX11),
-            RendererType::OpenGL => &concat_idents!($name, _GLSL),
-            RendererType::Metal => &concat_idents!($name, _MTL),
-            RendererType::OpenGLES => &concat_idents!($name, _ESSL),
-            RendererType::Vulkan => &concat_idents!($name, _SPV),
-            e => panic!("Unsupported renderer type {:#?}", e),
-        }
-    };
-}
 /*macro_rules! include_shader_code {
     ($name:ident) => {
         pub const $name = include_bytes!(stringify!($name));
     };
 }*/
-pub struct Renderer{
+/// Sampler uniform of the font and image textures, declared by `FS_OCORNUT_IMGUI`.
+pub const SAMPLER_UNIFORM: &str = "s_tex";
+/// Registered as the imgui context's renderer name, so a second renderer can be detected.
+const RENDERER_NAME: &str = "imgui_bgfx_renderer";
+const MAGENTA: [u8; 4] = [0xFF, 0x00, 0xFF, 0xFF];
+const BLACK: [u8; 4] = [0x00, 0x00, 0x00, 0xFF];
+/// RGBA8 pixels of the 2x2 texture drawn for unknown texture ids.
+pub const PLACEHOLDER_PIXELS: [[u8; 4]; 4] = [MAGENTA, BLACK, BLACK, MAGENTA];
+
+/// Whether the imgui shaders for `renderer_type` are compiled in, see the backend cargo features.
+pub fn supports(renderer_type : RendererType) -> bool {
+    VS_OCORNUT_IMGUI.get(renderer_type).is_some() && FS_OCORNUT_IMGUI.get(renderer_type).is_some()
+}
+
+/// Converts an imgui clip rect into a framebuffer scissor `[x, y, width, height]`,
+/// or `None` if the rect lies completely outside of the framebuffer.
+pub fn framebuffer_scissor(clip_rect : [f32; 4], clip_pos : [f32; 2], clip_scale : [f32; 2], fb_size : [f32; 2]) -> Option<[u16; 4]> {
+    let clip_rect = [
+        (clip_rect[0] - clip_pos[0]) * clip_scale[0],
+        (clip_rect[1] - clip_pos[1]) * clip_scale[1],
+        (clip_rect[2] - clip_pos[0]) * clip_scale[0],
+        (clip_rect[3] - clip_pos[1]) * clip_scale[1]
+    ];
+    if clip_rect[0] < fb_size[0] && clip_rect[1] < fb_size[1] && clip_rect[2] >= 0.0f32 && clip_rect[3] >= 0.0f32 {
+        let x = clip_rect[0].max(0.0f32) as u16;
+        let y = clip_rect[1].max(0.0f32) as u16;
+        Some([x, y, (clip_rect[2] as u16).saturating_sub(x), (clip_rect[3] as u16).saturating_sub(y)])
+    } else {
+        None
+    }
+}
+
+/// Same as `DrawData::draw_lists`, which builds a slice from a null pointer on frames
+/// where nothing was drawn.
+pub fn draw_lists(draw_data : &DrawData) -> impl Iterator<Item = &imgui::DrawList> {
+    (draw_data.draw_lists_count() > 0).then(|| draw_data.draw_lists()).into_iter().flatten()
+}
+
+/// Draw list bookkeeping of `Renderer::render`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RenderStats {
+    /// Draw lists submitted in the last frame.
+    pub draw_lists : u32,
+    /// Draw lists of the last frame that didn't fit into the transient buffers
+    /// and were drawn from the fallback buffers instead.
+    pub fallback_draw_lists : u32,
+    /// `fallback_draw_lists` summed up over all frames.
+    pub total_fallback_draw_lists : u64
+}
+
+/// Dynamic buffers used when bgfx runs out of transient vertex/index memory.
+/// They are kept across frames and recreated larger when a draw list outgrows them.
+struct FallbackBuffers {
+    vertices : bgfx::DynamicVertexBuffer,
+    indices : bgfx::DynamicIndexBuffer,
+    vertex_capacity : u32,
+    index_capacity : u32
+}
+
+/// Maps draw data coordinates to the framebuffer, see `framebuffer_scissor`.
+struct ClipSpace {
+    pos : [f32; 2],
+    scale : [f32; 2],
+    fb_size : [f32; 2]
+}
+
+enum DrawListBuffers {
+    Transient(bgfx::TransientVertexBuffer, bgfx::TransientIndexBuffer),
+    Fallback(usize)
+}
+
+/// Fields holding bgfx handles are declared in the order they are destroyed,
+/// see the `Drop` implementation.
+pub struct Renderer<'bgfx>{
+    fallback_buffers : Vec<FallbackBuffers>,
+    textures : imgui::Textures<bgfx::Texture>,
+    placeholder_texture : bgfx::Texture,
+    sampler_uniform : bgfx::Uniform,
     shader_program : bgfx::Program,
+    font_texture_id : imgui::TextureId,
     vertex_layout : bgfx::VertexLayoutBuilder,
-    font_atlas : bgfx::Texture,
-    sampler_uniform : bgfx::Uniform,
     view_id : bgfx::ViewId,
-    frame_time : Instant
+    stats : RenderStats,
+    /// Set by `watch_shaders`.
+    shader_watch : Option<shader_reload::ShaderWatch>,
+    draw_callbacks : DrawCallbacks<'bgfx>,
+    _bgfx : PhantomData<&'bgfx BgfxInstance>
 }
 
-impl Renderer {
-    pub fn init(imgui_context: &mut imgui::Context) -> Self {
+impl<'bgfx> Renderer<'bgfx> {
+    /// Fails if there are no shaders for the bgfx backend or `imgui_context` already has a renderer.
+    pub fn init(_bgfx : &'bgfx BgfxInstance, imgui_context: &mut imgui::Context) -> Result<Self> {
+        if let Some(name) = imgui_context.renderer_name() {
+            return Err(Error::RendererAlreadyInitialized(name.to_string()));
+        }
+        let renderer_type = bgfx::get_renderer_type();
+        let vs_code = VS_OCORNUT_IMGUI.code(renderer_type)?;
+        let fs_code = FS_OCORNUT_IMGUI.code(renderer_type)?;
+        Renderer::validate_shaders(vs_code, fs_code).map_err(Error::InvalidShader)?;
+        imgui_context.set_renderer_name(Some(RENDERER_NAME.to_string()));
         imgui_context.set_ini_filename(None);
-        let mut io = imgui_context.io_mut();
-        io.backend_flags = imgui::BackendFlags::RENDERER_HAS_VTX_OFFSET;
-        let texture = {
+        let io = imgui_context.io_mut();
+        io.backend_flags |= imgui::BackendFlags::RENDERER_HAS_VTX_OFFSET;
+        let mut textures = imgui::Textures::new();
+        let font_texture_id = {
             let mut fonts = imgui_context.fonts();
-            let font_atlas = fonts.build_rgba32_texture();
-            bgfx::create_texture_2d(font_atlas.width as u16, font_atlas.height as u16, false, 1, bgfx::TextureFormat::BGRA8, 0, &Memory::copy(&font_atlas.data))
+            let id = textures.insert(Renderer::create_font_texture(&mut fonts));
+            fonts.tex_id = id;
+            id
         };
-        Self {
-            shader_program: {
-                let vsh = bgfx::create_shader(&Memory::copy(get_shader_code!(VS_OCORNUT_IMGUI)));
-                let fsh = bgfx::create_shader(&Memory::copy(get_shader_code!(FS_OCORNUT_IMGUI)));
-                //let vsh = bgfx::create_shader(&Memory::copy(&vs_ocornut_imgui::vs_ocornut_imgui_glsl));
-                //let fsh = bgfx::create_shader(&Memory::copy(&fs_ocornut_imgui::fs_ocornut_imgui_glsl));
-                bgfx::create_program(&vsh, &fsh, false)
-                //bgfx::create_program(&vsh, &fsh, true) //TODO: Why Segmentation fault if we destroy the shaders?
-            },
+        Ok(Self {
+            shader_program: Renderer::create_program(vs_code, fs_code),
             vertex_layout: {
                 let layout = bgfx::VertexLayoutBuilder::new();
                 layout.begin(bgfx::RendererType::Noop);
@@ -70,101 +161,309 @@ impl Renderer {
                 layout
             },
             sampler_uniform: {
-                bgfx::Uniform::create("s_tex", bgfx::UniformType::Sampler, 1)
+                bgfx::Uniform::create(SAMPLER_UNIFORM, bgfx::UniformType::Sampler, 1)
             },
-            font_atlas: texture,
+            fallback_buffers: Vec::new(),
+            textures,
+            font_texture_id,
+            placeholder_texture: Renderer::create_placeholder_texture(),
             view_id: 0xFF,
-            frame_time: Instant::now()
+            stats: RenderStats::default(),
+            shader_watch: None,
+            draw_callbacks: DrawCallbacks::default(),
+            _bgfx: PhantomData
+        })
+    }
+    fn create_program(vs_code : &[u8], fs_code : &[u8]) -> bgfx::Program {
+        let vsh = bgfx::create_shader(&Memory::copy(vs_code));
+        let fsh = bgfx::create_shader(&Memory::copy(fs_code));
+        let program = bgfx::create_program(&vsh, &fsh, true);
+        // The program owns the shaders now and destroys them together with itself.
+        // bgfx::Shader destroys its handle on drop as well, so letting the wrappers
+        // drop here destroyed every shader twice (the old segfault).
+        std::mem::forget(vsh);
+        std::mem::forget(fsh);
+        program
+    }
+    /// Checks the blobs before bgfx gets them, it silently draws nothing with broken ones.
+    fn validate_shaders(vs_code : &[u8], fs_code : &[u8]) -> std::result::Result<(), String> {
+        let vertex = shader_binary::ShaderBinary::parse(vs_code).map_err(|e| format!("vertex shader: {}", e))?;
+        let fragment = shader_binary::ShaderBinary::parse(fs_code).map_err(|e| format!("fragment shader: {}", e))?;
+        shader_binary::validate_program(&vertex, &fragment, &[(SAMPLER_UNIFORM, bgfx::UniformType::Sampler)])
+    }
+    fn create_font_texture(fonts : &mut imgui::FontAtlas) -> bgfx::Texture {
+        let font_atlas = fonts.build_rgba32_texture();
+        bgfx::create_texture_2d(font_atlas.width as u16, font_atlas.height as u16, false, 1, bgfx::TextureFormat::BGRA8, 0, &Memory::copy(font_atlas.data))
+    }
+    /// Rebuilds the font atlas and uploads it again, e.g. after fonts were added or resized.
+    /// Must not be called between `imgui::Context::frame` and `Ui::render`.
+    pub fn reload_font_texture(&mut self, imgui_context : &mut imgui::Context) {
+        let mut fonts = imgui_context.fonts();
+        let texture = Renderer::create_font_texture(&mut fonts);
+        fonts.tex_id = self.font_texture_id;
+        // dropping the old texture destroys it, bgfx defers that until the current frame is done
+        self.textures.replace(self.font_texture_id, texture);
+    }
+    /// Magenta/black checkerboard bound for texture ids that are not registered,
+    /// so a missing texture shows up on screen instead of crashing the frame.
+    fn create_placeholder_texture() -> bgfx::Texture {
+        let flags = SamplerFlags::POINT.bits();
+        bgfx::create_texture_2d(2, 2, false, 1, bgfx::TextureFormat::RGBA8, flags as u64, &Memory::copy(&PLACEHOLDER_PIXELS.concat()))
+    }
+    /// Registers a bgfx texture so it can be drawn with `ui.image()` / `ImageButton`.
+    /// The renderer owns the texture until it is unregistered again.
+    pub fn register_texture(&mut self, texture : bgfx::Texture) -> imgui::TextureId {
+        self.textures.insert(texture)
+    }
+    /// Removes a texture registered with `register_texture` and hands it back to the caller.
+    /// The font atlas id is reserved and can't be unregistered.
+    pub fn unregister_texture(&mut self, texture_id : imgui::TextureId) -> Option<bgfx::Texture> {
+        if texture_id == self.font_texture_id {
+            return None;
         }
+        self.textures.remove(texture_id)
+    }
+    pub fn font_texture_id(&self) -> imgui::TextureId {
+        self.font_texture_id
     }
-    pub fn begin_frame(&mut self, imgui_context: &mut imgui::Context, mouse_position : [f32; 2], mouse_button : u8, mouse_scroll : f32, size : (i32, i32), input_char : char, view_id : bgfx::ViewId)  {
+    /// Draws imgui's output into `view_id`. Input, display size and timing are up to the
+    /// platform layer, e.g. `GlfwPlatform::prepare_frame`.
+    pub fn render(&mut self, view_id : bgfx::ViewId, draw_data: &DrawData) {
         self.view_id = view_id;
-        let mut io = imgui_context.io_mut();
-        io.mouse_pos = mouse_position;
-        for i in 0..5 {
-            io.mouse_down[i] = (mouse_button & (0x01 << i)) != 0x00;
+        let clip = match self.begin_render(draw_data.display_pos, draw_data.display_size, draw_data.framebuffer_scale) {
+            Some(clip) => clip,
+            None => return,
+        };
+        for draw_list in draw_lists(draw_data) {
+            self.render_draw_list(&clip, draw_list.vtx_buffer(), draw_list.idx_buffer(), draw_list.commands(), Some(draw_list));
         }
-        io.mouse_wheel = mouse_scroll;
-        io.display_size = [size.0 as f32, size.1 as f32];
-        if input_char != '\0' {
-            io.add_input_character(input_char);
+        self.draw_callbacks.clear();
+        self.stats.total_fallback_draw_lists += self.stats.fallback_draw_lists as u64;
+    }
+    /// Replays a frame recorded with `draw_capture`, going through the same path as `render`.
+    pub fn render_captured(&mut self, view_id : bgfx::ViewId, frame : &CapturedFrame) {
+        self.view_id = view_id;
+        let clip = match self.begin_render(frame.display_pos, frame.display_size, frame.framebuffer_scale) {
+            Some(clip) => clip,
+            None => return,
+        };
+        for draw_list in &frame.draw_lists {
+            let commands = draw_list.commands.iter().map(|command| command.to_draw_cmd());
+            self.render_draw_list(&clip, &draw_list.vertices, &draw_list.indices, commands, None);
         }
-        io.delta_time = self.frame_time.elapsed().as_secs_f32();
-        self.frame_time = Instant::now();
+        self.stats.total_fallback_draw_lists += self.stats.fallback_draw_lists as u64;
     }
-
-    pub fn render(&mut self, draw_data: &DrawData) {
+    fn begin_render(&mut self, display_pos : [f32; 2], display_size : [f32; 2], framebuffer_scale : [f32; 2]) -> Option<ClipSpace> {
+        self.reload_changed_shaders();
         //let view_id : bgfx::ViewId = 255;
-        let index_32 = std::mem::size_of::<imgui::DrawIdx>() == 4;
-        let fb_width = draw_data.display_size[0] * draw_data.framebuffer_scale[0];
-        let fb_height = draw_data.display_size[1] * draw_data.framebuffer_scale[1];
+        let fb_width = display_size[0] * framebuffer_scale[0];
+        let fb_height = display_size[1] * framebuffer_scale[1];
         if fb_width <= 0.0 || fb_height <= 0.0 {
-            return;
+            return None;
         }
         bgfx::set_view_mode(self.view_id, bgfx::ViewMode::Sequential);
         //let caps = bgfx::get_caps();
         {
-            let x = draw_data.display_pos[0];
-            let y = draw_data.display_pos[1];
-            let width = draw_data.display_size[0];
-            let height = draw_data.display_size[1];
+            let x = display_pos[0];
+            let y = display_pos[1];
+            let width = display_size[0];
+            let height = display_size[1];
             let projection = glam::Mat4::orthographic_lh(x, x + width, y + height, y, 0.0f32, 1000.0f32);
-            bgfx::set_view_transform(self.view_id, &glam::Mat4::IDENTITY.as_ref(), &projection.as_ref());
-            bgfx::set_view_rect(self.view_id, 0, 0, width as u16, height as u16);
+            bgfx::set_view_transform(self.view_id, glam::Mat4::IDENTITY.as_ref(), projection.as_ref());
+            bgfx::set_view_rect(self.view_id, 0, 0, fb_width as u16, fb_height as u16);
         }
-
-        let clip_pos   = draw_data.display_pos;       // (0,0) unless using multi-viewports
-        let clip_scale = draw_data.framebuffer_scale; // (1,1) unless using retina display which are often (2,2)
-
-        for draw_list in draw_data.draw_lists() {
-            let vertices_count=  draw_list.vtx_buffer().len() as u32;
-            let indices_count = draw_list.idx_buffer().len() as u32;
-            if bgfx::get_avail_transient_vertex_buffer(vertices_count, &self.vertex_layout) != vertices_count || bgfx::get_avail_transient_index_buffer(indices_count, index_32) != indices_count {
-                break;
-            }
+        self.stats.draw_lists = 0;
+        self.stats.fallback_draw_lists = 0;
+        Some(ClipSpace {
+            pos: display_pos,         // (0,0) unless using multi-viewports
+            scale: framebuffer_scale, // (1,1) unless using retina display which are often (2,2)
+            fb_size: [fb_width, fb_height]
+        })
+    }
+    /// `draw_list` is only needed to run callbacks, replayed lists don't have any.
+    fn render_draw_list<I : Iterator<Item = imgui::DrawCmd>>(&mut self, clip : &ClipSpace, vertices : &[imgui::DrawVert], indices : &[imgui::DrawIdx], commands : I, draw_list : Option<&imgui::DrawList>) {
+        let index_32 = std::mem::size_of::<imgui::DrawIdx>() == 4;
+        let vertices_count = vertices.len() as u32;
+        let indices_count = indices.len() as u32;
+        let buffers = if bgfx::get_avail_transient_vertex_buffer(vertices_count, &self.vertex_layout) == vertices_count && bgfx::get_avail_transient_index_buffer(indices_count, index_32) == indices_count {
             let mut tvb = bgfx::TransientVertexBuffer::new();
             let mut tib = bgfx::TransientIndexBuffer::new();
 
             bgfx::alloc_transient_vertex_buffer(&mut tvb, vertices_count, &self.vertex_layout);
             bgfx::alloc_transient_index_buffer(&mut tib, indices_count, index_32);
 
-            unsafe {  std::ptr::copy_nonoverlapping(draw_list.vtx_buffer().as_ptr() as *const u8, tvb.data as *mut u8, std::mem::size_of::<imgui::DrawVert>() * vertices_count as usize); }
-            unsafe {  std::ptr::copy_nonoverlapping(draw_list.idx_buffer().as_ptr() as *const u8, tib.data as *mut u8, std::mem::size_of::<imgui::DrawIdx>() * indices_count as usize); }
-
-            let encoder = bgfx::encoder_begin(false);
-            for command in draw_list.commands() {
-                match command {
-                    imgui::DrawCmd::Elements { count, cmd_params } => {
-                        let state = StateWriteFlags::RGB.bits() | StateWriteFlags::A.bits() | StateFlags::MSAA.bits() | StateBlendFlags::SRC_ALPHA.bits() | (StateBlendFlags::INV_SRC_ALPHA.bits() << 4) | (StateBlendFlags::SRC_ALPHA.bits() << 8) | (StateBlendFlags::INV_SRC_ALPHA.bits() << 12);
-                        let clip_rect = [
-                            (cmd_params.clip_rect[0] - clip_pos[0]) * clip_scale[0],
-                            (cmd_params.clip_rect[1] - clip_pos[1]) * clip_scale[1],
-                            (cmd_params.clip_rect[2] - clip_pos[0]) * clip_scale[0],
-                            (cmd_params.clip_rect[3] - clip_pos[1]) * clip_scale[1]
-                        ];
-                        if clip_rect[0] <  fb_width && clip_rect[1] < fb_height &&  clip_rect[2] >= 0.0f32 &&  clip_rect[3] >= 0.0f32 {
-                            let xx = clip_rect[0].max(0.0f32) as u16;
-                            let yy = clip_rect[1].max(0.0f32) as u16;
-                            encoder.set_scissor(xx, yy, (clip_rect[2].min(f32::MAX) as u16) -xx, (clip_rect[3].min(f32::MAX) as u16) - yy);
-                            encoder.set_state(state, 0);
-                            encoder.set_texture(0, &self.sampler_uniform, &self.font_atlas, u32::MAX);
-                            encoder.set_transient_vertex_buffer(0, &tvb, cmd_params.vtx_offset as u32, vertices_count);
-                            encoder.set_transient_index_buffer(&tib, cmd_params.idx_offset as u32, count as u32);
-                            encoder.submit(self.view_id, &self.shader_program, SubmitArgs::default());
+            unsafe {  std::ptr::copy_nonoverlapping(vertices.as_ptr() as *const u8, tvb.data as *mut u8, std::mem::size_of::<imgui::DrawVert>() * vertices_count as usize); }
+            unsafe {  std::ptr::copy_nonoverlapping(indices.as_ptr() as *const u8, tib.data as *mut u8, std::mem::size_of::<imgui::DrawIdx>() * indices_count as usize); }
+            DrawListBuffers::Transient(tvb, tib)
+        } else {
+            // Every fallback list of a frame needs its own buffers, updates only take effect
+            // when the frame is submitted.
+            let slot = self.stats.fallback_draw_lists as usize;
+            match self.fallback_buffers.get(slot) {
+                None => self.fallback_buffers.push(self.create_fallback_buffers(vertices_count, indices_count, index_32)),
+                Some(fallback) if fallback.vertex_capacity < vertices_count || fallback.index_capacity < indices_count => {
+                    // bgfx destroys the replaced buffers once the frames using them are done
+                    let grown = self.create_fallback_buffers(fallback.vertex_capacity.max(vertices_count), fallback.index_capacity.max(indices_count), index_32);
+                    self.fallback_buffers[slot] = grown;
+                },
+                Some(_) => {},
+            }
+            let fallback = &self.fallback_buffers[slot];
+            fallback.vertices.update_dynamic_vertex_buffer(0, &Memory::copy(vertices));
+            fallback.indices.update_dynamic_index_buffer(0, &Memory::copy(indices));
+            self.stats.fallback_draw_lists += 1;
+            DrawListBuffers::Fallback(slot)
+        };
+        self.stats.draw_lists += 1;
+
+        let encoder = bgfx::encoder_begin(false);
+        let mut draw_state = DrawState::default();
+        for command in commands {
+            match command {
+                imgui::DrawCmd::Elements { count, cmd_params } => {
+                    if let Some([x, y, width, height]) = framebuffer_scissor(cmd_params.clip_rect, clip.pos, clip.scale, clip.fb_size) {
+                        encoder.set_scissor(x, y, width, height);
+                        encoder.set_state(draw_state.state, draw_state.blend_factor);
+                        for (uniform, value) in &draw_state.uniforms {
+                            encoder.set_uniform(uniform, value, 1);
                         }
-                    },
-                    imgui::DrawCmd::RawCallback { callback, raw_cmd } => unsafe {
-                        callback(draw_list.raw(), raw_cmd);
-                    },
-                    imgui::DrawCmd::ResetRenderState => {
-                        bgfx::reset(fb_width as u32, fb_height as u32, ResetArgs::default());
+                        let texture = self.textures.get(cmd_params.texture_id).unwrap_or(&self.placeholder_texture);
+                        encoder.set_texture(0, &self.sampler_uniform, texture, u32::MAX);
+                        match &buffers {
+                            DrawListBuffers::Transient(tvb, tib) => {
+                                encoder.set_transient_vertex_buffer(0, tvb, cmd_params.vtx_offset as u32, vertices_count);
+                                encoder.set_transient_index_buffer(tib, cmd_params.idx_offset as u32, count as u32);
+                            },
+                            DrawListBuffers::Fallback(slot) => {
+                                let fallback = &self.fallback_buffers[*slot];
+                                encoder.set_dynamic_vertex_buffer(0, &fallback.vertices, cmd_params.vtx_offset as u32, vertices_count);
+                                encoder.set_dynamic_index_buffer(&fallback.indices, cmd_params.idx_offset as u32, count as u32);
+                            }
+                        }
+                        let program = draw_state.program.unwrap_or(&self.shader_program);
+                        encoder.submit(self.view_id, program, SubmitArgs::default());
+                    }
+                },
+                imgui::DrawCmd::RawCallback { callback, raw_cmd } => {
+                    if let Some(draw_callback) = self.draw_callbacks.take(callback, raw_cmd) {
+                        let clip_rect = unsafe { (*raw_cmd).ClipRect };
+                        let mut context = DrawCallbackContext {
+                            encoder,
+                            view_id: self.view_id,
+                            scissor: framebuffer_scissor([clip_rect.x, clip_rect.y, clip_rect.z, clip_rect.w], clip.pos, clip.scale, clip.fb_size),
+                            state: &mut draw_state
+                        };
+                        draw_callback(&mut context);
+                    } else if let Some(draw_list) = draw_list {
+                        unsafe { callback(draw_list.raw(), raw_cmd); }
                     }
+                },
+                imgui::DrawCmd::ResetRenderState => {
+                    draw_state = DrawState::default();
                 }
             }
-            bgfx::encoder_end(&encoder);
         }
+        bgfx::encoder_end(encoder);
+    }
+    fn create_fallback_buffers(&self, vertex_capacity : u32, index_capacity : u32, index_32 : bool) -> FallbackBuffers {
+        let index_flags = if index_32 { BufferFlags::INDEX_32.bits() } else { BufferFlags::NONE.bits() };
+        FallbackBuffers {
+            vertices: bgfx::create_dynamic_vertex_buffer(vertex_capacity, &self.vertex_layout, BufferFlags::NONE.bits()),
+            indices: bgfx::create_dynamic_index_buffer(index_capacity, index_flags),
+            vertex_capacity,
+            index_capacity
+        }
+    }
+    /// Draw list counters, including the lists that had to use the fallback buffers.
+    pub fn stats(&self) -> RenderStats {
+        self.stats
+    }
+    /// Handle for adding closures to the draw lists that change the program, state or uniforms
+    /// of the following commands, or draw on their own, see [`DrawCallbacks`].
+    pub fn draw_callbacks(&self) -> DrawCallbacks<'bgfx> {
+        self.draw_callbacks.clone()
     }
     pub fn get_shader(&self) -> &bgfx::Program {
         &self.shader_program
     }
+    /// Development mode: from now on every frame checks `dir` for changed shader blobs of the
+    /// running backend and swaps the program, see [`shader_reload`].
+    pub fn watch_shaders<P : AsRef<std::path::Path>>(&mut self, dir : P) -> Result<()> {
+        let renderer_type = bgfx::get_renderer_type();
+        let watch = shader_reload::ShaderWatch::new(dir.as_ref(), renderer_type).map_err(|_| Error::UnsupportedRenderer(renderer_type))?;
+        self.shader_watch = Some(watch);
+        Ok(())
+    }
+    fn reload_changed_shaders(&mut self) {
+        let changed = match self.shader_watch.as_mut() {
+            Some(watch) => watch.poll(Renderer::validate_shaders),
+            None => None,
+        };
+        // the old program is destroyed on drop, bgfx defers that until it is no longer used
+        if let Some((vs_code, fs_code)) = changed {
+            self.shader_program = Renderer::create_program(&vs_code, &fs_code);
+        }
+    }
+    /// Why the watched shaders couldn't be reloaded, the previous program is still in use.
+    pub fn shader_error(&self) -> Option<&str> {
+        self.shader_watch.as_ref().and_then(|watch| watch.error())
+    }
+    /// Shows `shader_error` in a window, call it while building the UI.
+    pub fn show_shader_errors(&self, ui : &imgui::Ui) {
+        if let Some(error) = self.shader_error() {
+            imgui::Window::new("Shader reload failed")
+                .position([10.0, 10.0], imgui::Condition::FirstUseEver)
+                .always_auto_resize(true)
+                .build(ui, || {
+                    ui.text_colored([1.0, 0.3, 0.3, 1.0], error);
+                    ui.text_disabled("the last working shaders are still in use");
+                });
+        }
+    }
+}
+
+impl Drop for Renderer<'_> {
+    fn drop(&mut self) {
+        // Fallback buffers and registered textures (font atlas included) go first, the remaining
+        // handles follow in field declaration order: placeholder texture, sampler uniform, shader program.
+        self.fallback_buffers.clear();
+        self.textures = imgui::Textures::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::headless::{init_bgfx_headless_with, HeadlessApp};
+    use crate::test_support::lock_globals;
+
+    #[test]
+    fn falls_back_to_growing_dynamic_buffers_without_transient_memory() {
+        let _lock = lock_globals();
+        let bgfx_instance = init_bgfx_headless_with(|init| {
+            init.limits.transient_vb_size = 64;
+            init.limits.transient_ib_size = 64;
+        }).unwrap();
+        let mut app = HeadlessApp::new(&bgfx_instance, imgui::Context::create()).unwrap();
+        // imgui skips new windows in their first frame
+        for _ in 0..2 {
+            app.frame(&[], |ui| {
+                imgui::Window::new("small").build(ui, || ui.text("x"));
+            });
+        }
+        let stats = app.render_stats();
+        assert!(stats.fallback_draw_lists > 0);
+        assert_eq!(stats.fallback_draw_lists, stats.draw_lists);
+        let capacity = app.renderer().fallback_buffers[0].vertex_capacity;
+
+        // a larger list in the same slot gets new buffers
+        let total = stats.total_fallback_draw_lists;
+        let mut demo_open = true;
+        for _ in 0..2 {
+            app.frame(&[], |ui| ui.show_demo_window(&mut demo_open));
+        }
+        let stats = app.render_stats();
+        assert_eq!(stats.fallback_draw_lists, stats.draw_lists);
+        assert!(stats.total_fallback_draw_lists > total);
+        assert!(app.renderer().fallback_buffers[0].vertex_capacity > capacity);
+    }
 }
@@ -0,0 +1,102 @@
+//! Native window handles to bgfx `PlatformData`.
+use bgfx_rs::bgfx;
+use raw_window_handle::RawWindowHandle;
+
+/// Fills in the handles bgfx needs to render into a window, `None` for window systems
+/// bgfx doesn't support.
+///
+/// bgfx reads `nwh` as the window (the surface on Wayland) and `ndt` as the display or
+/// connection it belongs to. The bgfx version wrapped by bgfx-rs has no field telling it the
+/// window system, on Linux it assumes X11 for everything but the handles above.
+pub fn platform_data(handle : RawWindowHandle) -> Option<bgfx::PlatformData> {
+    let mut pd = bgfx::PlatformData::new();
+    match handle {
+        #[cfg(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+        ))]
+        RawWindowHandle::Xlib(data) => {
+            pd.nwh = data.window as *mut _;
+            pd.ndt = data.display as *mut _;
+        }
+        #[cfg(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+        ))]
+        RawWindowHandle::Xcb(data) => {
+            pd.nwh = data.window as usize as *mut _;
+            pd.ndt = data.connection as *mut _;
+        }
+        #[cfg(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+        ))]
+        RawWindowHandle::Wayland(data) => {
+            pd.nwh = data.surface as *mut _;
+            pd.ndt = data.display as *mut _;
+        }
+        #[cfg(target_os = "macos")]
+        RawWindowHandle::MacOS(data) => {
+            pd.nwh = data.ns_window;
+        }
+        #[cfg(target_os = "windows")]
+        RawWindowHandle::Windows(data) => {
+            pd.nwh = data.hwnd;
+        }
+        #[cfg(target_os = "android")]
+        RawWindowHandle::Android(data) => {
+            pd.nwh = data.a_native_window;
+        }
+        _ => return None,
+    }
+    Some(pd)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use raw_window_handle::unix::{WaylandHandle, XcbHandle, XlibHandle};
+    use super::*;
+
+    fn fake_pointer(address : usize) -> *mut std::ffi::c_void {
+        address as *mut _
+    }
+
+    #[test]
+    fn maps_xlib_handles() {
+        let mut handle = XlibHandle::empty();
+        handle.window = 0x4200001;
+        handle.display = fake_pointer(0x1000);
+        let pd = platform_data(RawWindowHandle::Xlib(handle)).unwrap();
+        assert_eq!(pd.nwh as usize, 0x4200001);
+        assert_eq!(pd.ndt as usize, 0x1000);
+    }
+
+    #[test]
+    fn maps_xcb_handles() {
+        let mut handle = XcbHandle::empty();
+        handle.window = 0x4200002;
+        handle.connection = fake_pointer(0x2000);
+        let pd = platform_data(RawWindowHandle::Xcb(handle)).unwrap();
+        assert_eq!(pd.nwh as usize, 0x4200002);
+        assert_eq!(pd.ndt as usize, 0x2000);
+    }
+
+    #[test]
+    fn maps_wayland_surface_to_the_window_handle() {
+        let mut handle = WaylandHandle::empty();
+        handle.surface = fake_pointer(0x3000);
+        handle.display = fake_pointer(0x4000);
+        let pd = platform_data(RawWindowHandle::Wayland(handle)).unwrap();
+        assert_eq!(pd.nwh as usize, 0x3000);
+        assert_eq!(pd.ndt as usize, 0x4000);
+    }
+}
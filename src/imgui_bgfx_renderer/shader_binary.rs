@@ -0,0 +1,271 @@
+//! Parser for the shader binaries shaderc writes and `bgfx::create_shader` reads.
+//!
+//! All values are little endian:
+//!
+//! ```text
+//! magic        [u8; 4]   b"VSH", b"FSH" or b"CSH" followed by the format version
+//! hash_in      u32       hash of the inputs, 0 for vertex shaders
+//! hash_out     u32       hash of the outputs (version 6+), 0 for fragment shaders
+//! count        u16       uniforms, each:
+//!   name_size  u8
+//!   name       [u8; name_size]
+//!   type       u8        bgfx::UniformType | fragment 0x10 | sampler 0x20 | read only 0x40 | compare 0x80
+//!   num        u8
+//!   reg_index  u16
+//!   reg_count  u16
+//!   tex_info   u16       (version 8+)
+//!   tex_format u16       (version 10+)
+//! code_size    u32
+//! code         [u8; code_size]
+//! 0            u8
+//! attributes   u8        optional, attribute ids follow as u16 each
+//! size         u16       optional, size of the constant buffer
+//! ```
+//!
+//! bgfx only reports broken blobs in debug builds and then silently draws nothing, so
+//! `Renderer::init` checks the blobs with [`validate_program`] before handing them over.
+use bgfx_rs::bgfx::{Attrib, UniformType};
+
+pub const UNIFORM_FRAGMENT_BIT: u8 = 0x10;
+pub const UNIFORM_SAMPLER_BIT: u8 = 0x20;
+pub const UNIFORM_READ_ONLY_BIT: u8 = 0x40;
+pub const UNIFORM_COMPARE_BIT: u8 = 0x80;
+const UNIFORM_FLAGS: u8 = UNIFORM_FRAGMENT_BIT | UNIFORM_SAMPLER_BIT | UNIFORM_READ_ONLY_BIT | UNIFORM_COMPARE_BIT;
+
+/// Oldest format version bgfx still loads.
+const MIN_VERSION: u8 = 5;
+
+/// Stable ids bgfx stores vertex attributes with, see `s_attribToId` in bgfx's vertexlayout.cpp.
+const ATTRIBUTE_IDS: [(u16, Attrib); 18] = [
+    (0x0001, Attrib::Position), (0x0002, Attrib::Normal), (0x0003, Attrib::Tangent), (0x0004, Attrib::Bitangent),
+    (0x0005, Attrib::Color0), (0x0006, Attrib::Color1), (0x0018, Attrib::Color2), (0x0019, Attrib::Color3),
+    (0x000e, Attrib::Indices), (0x000f, Attrib::Weight),
+    (0x0010, Attrib::TexCoord0), (0x0011, Attrib::TexCoord1), (0x0012, Attrib::TexCoord2), (0x0013, Attrib::TexCoord3),
+    (0x0014, Attrib::TexCoord4), (0x0015, Attrib::TexCoord5), (0x0016, Attrib::TexCoord6), (0x0017, Attrib::TexCoord7),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShaderKind {
+    Vertex,
+    Fragment,
+    Compute
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShaderUniform {
+    pub name : String,
+    /// `End` marks entries bgfx skips, e.g. the separate texture and sampler objects of Metal.
+    pub uniform_type : UniformType,
+    /// `UNIFORM_*_BIT`s.
+    pub flags : u8,
+    pub num : u8,
+    pub reg_index : u16,
+    pub reg_count : u16,
+    pub tex_info : u16,
+    pub tex_format : u16
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShaderBinary<'a> {
+    pub kind : ShaderKind,
+    pub version : u8,
+    pub hash_in : u32,
+    pub hash_out : u32,
+    pub uniforms : Vec<ShaderUniform>,
+    /// The backend specific part: GLSL source, SPIR-V, DXBC, Metal source.
+    pub code : &'a [u8],
+    /// Inputs of a vertex shader, `None` for profiles that don't write the attribute table.
+    pub attributes : Option<Vec<Attrib>>,
+    pub constant_buffer_size : Option<u16>
+}
+
+struct Reader<'a> {
+    data : &'a [u8],
+    position : usize
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, count : usize, what : &str) -> Result<&'a [u8], String> {
+        let bytes = self.data.get(self.position..self.position + count)
+            .ok_or_else(|| format!("truncated at offset {} reading {}", self.position, what))?;
+        self.position += count;
+        Ok(bytes)
+    }
+    fn u8(&mut self, what : &str) -> Result<u8, String> {
+        Ok(self.bytes(1, what)?[0])
+    }
+    fn u16(&mut self, what : &str) -> Result<u16, String> {
+        let bytes = self.bytes(2, what)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+    fn u32(&mut self, what : &str) -> Result<u32, String> {
+        let bytes = self.bytes(4, what)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+    fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+}
+
+fn uniform_type(value : u8) -> Result<UniformType, String> {
+    match value & !UNIFORM_FLAGS {
+        0 => Ok(UniformType::Sampler),
+        1 => Ok(UniformType::End),
+        2 => Ok(UniformType::Vec4),
+        3 => Ok(UniformType::Mat3),
+        4 => Ok(UniformType::Mat4),
+        other => Err(format!("unknown uniform type {}", other)),
+    }
+}
+
+impl<'a> ShaderBinary<'a> {
+    pub fn parse(data : &'a [u8]) -> Result<ShaderBinary<'a>, String> {
+        let mut reader = Reader { data, position: 0 };
+        let magic = reader.bytes(4, "magic")?;
+        let kind = match &magic[..3] {
+            b"VSH" => ShaderKind::Vertex,
+            b"FSH" => ShaderKind::Fragment,
+            b"CSH" => ShaderKind::Compute,
+            _ => return Err(format!("not a bgfx shader, magic {:02x?}", magic)),
+        };
+        let version = magic[3];
+        if version < MIN_VERSION {
+            return Err(format!("unsupported shader format version {}", version));
+        }
+        let hash_in = reader.u32("input hash")?;
+        let hash_out = if version >= 6 { reader.u32("output hash")? } else { hash_in };
+        let count = reader.u16("uniform count")?;
+        let mut uniforms = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let name_size = reader.u8("uniform name size")?;
+            let name = std::str::from_utf8(reader.bytes(name_size as usize, "uniform name")?)
+                .map_err(|_| "uniform name is not UTF-8".to_string())?
+                .to_string();
+            let type_and_flags = reader.u8("uniform type")?;
+            uniforms.push(ShaderUniform {
+                uniform_type: uniform_type(type_and_flags).map_err(|e| format!("uniform {}: {}", name, e))?,
+                flags: type_and_flags & UNIFORM_FLAGS,
+                num: reader.u8("uniform num")?,
+                reg_index: reader.u16("uniform register index")?,
+                reg_count: reader.u16("uniform register count")?,
+                tex_info: if version >= 8 { reader.u16("uniform texture info")? } else { 0 },
+                tex_format: if version >= 10 { reader.u16("uniform texture format")? } else { 0 },
+                name
+            });
+        }
+        let code_size = reader.u32("code size")?;
+        let code = reader.bytes(code_size as usize, "code")?;
+        if reader.u8("code terminator")? != 0 {
+            return Err("code is not followed by a 0 byte".to_string());
+        }
+        let (attributes, constant_buffer_size) = if reader.remaining() > 0 {
+            let attribute_count = reader.u8("attribute count")?;
+            let mut attributes = Vec::with_capacity(attribute_count as usize);
+            for _ in 0..attribute_count {
+                let id = reader.u16("attribute id")?;
+                let attribute = ATTRIBUTE_IDS.iter().find(|(attribute_id, _)| *attribute_id == id)
+                    .ok_or_else(|| format!("unknown attribute id {:#06x}", id))?;
+                attributes.push(attribute.1);
+            }
+            (Some(attributes), Some(reader.u16("constant buffer size")?))
+        } else {
+            (None, None)
+        };
+        if reader.remaining() > 0 {
+            return Err(format!("{} trailing bytes", reader.remaining()));
+        }
+        Ok(ShaderBinary { kind, version, hash_in, hash_out, uniforms, code, attributes, constant_buffer_size })
+    }
+
+    /// The uniform bgfx creates for `name`, skipping `End` entries.
+    pub fn uniform(&self, name : &str) -> Option<&ShaderUniform> {
+        self.uniforms.iter().find(|uniform| uniform.name == name && uniform.uniform_type != UniformType::End)
+    }
+}
+
+/// Checks what `bgfx::create_program` and the draw calls rely on: a vertex and a fragment
+/// shader whose interface hashes match, and every uniform in `uniforms` declared by one of
+/// them with the given type.
+pub fn validate_program(vertex : &ShaderBinary, fragment : &ShaderBinary, uniforms : &[(&str, UniformType)]) -> Result<(), String> {
+    if vertex.kind != ShaderKind::Vertex {
+        return Err(format!("expected a vertex shader, got a {:?} shader", vertex.kind));
+    }
+    if fragment.kind != ShaderKind::Fragment {
+        return Err(format!("expected a fragment shader, got a {:?} shader", fragment.kind));
+    }
+    if vertex.hash_out != fragment.hash_in {
+        return Err(format!("vertex shader outputs {:#010x} don't match fragment shader inputs {:#010x}", vertex.hash_out, fragment.hash_in));
+    }
+    for (name, uniform_type) in uniforms {
+        match vertex.uniform(name).or_else(|| fragment.uniform(name)) {
+            Some(uniform) if uniform.uniform_type == *uniform_type => {},
+            Some(uniform) => return Err(format!("uniform {} is a {:?}, expected a {:?}", name, uniform.uniform_type, uniform_type)),
+            None => return Err(format!("uniform {} is not declared by the shaders", name)),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::imgui_bgfx_renderer::{ShaderSet, FS_OCORNUT_IMGUI, SAMPLER_UNIFORM, VS_OCORNUT_IMGUI};
+
+    /// The profiles embedded with the enabled backend features.
+    fn profiles(set : &ShaderSet) -> Vec<(&'static str, &'static [u8])> {
+        let optional = [("essl", set.essl), ("spirv", set.spv), ("dx9", set.dx9), ("dx11", set.dx11), ("metal", set.mtl)];
+        std::iter::once(("glsl", set.glsl)).chain(optional.into_iter().filter_map(|(profile, blob)| Some((profile, blob?)))).collect()
+    }
+
+    #[test]
+    fn parses_every_embedded_blob() {
+        let stages = [(VS_OCORNUT_IMGUI, ShaderKind::Vertex, b"VSH", "u_viewProj"), (FS_OCORNUT_IMGUI, ShaderKind::Fragment, b"FSH", SAMPLER_UNIFORM)];
+        for (set, kind, magic, uniform) in stages {
+            for (profile, blob) in profiles(&set) {
+                assert_eq!(&blob[..3], magic, "{}", profile);
+                let shader = ShaderBinary::parse(blob).unwrap_or_else(|e| panic!("{} {:?}: {}", profile, kind, e));
+                assert_eq!(shader.kind, kind, "{}", profile);
+                assert!(!shader.code.is_empty(), "{}", profile);
+                assert!(shader.uniform(uniform).is_some(), "{} doesn't declare {}", profile, uniform);
+                if let (ShaderKind::Vertex, Some(attributes)) = (kind, &shader.attributes) {
+                    let mut attributes = attributes.iter().map(|attribute| *attribute as u32).collect::<Vec<_>>();
+                    attributes.sort_unstable();
+                    let mut expected = [Attrib::Position as u32, Attrib::TexCoord0 as u32, Attrib::Color0 as u32];
+                    expected.sort_unstable();
+                    assert_eq!(attributes, expected, "{}", profile);
+                }
+            }
+        }
+        let glsl = ShaderBinary::parse(FS_OCORNUT_IMGUI.glsl).unwrap();
+        assert!(std::str::from_utf8(glsl.code).unwrap().contains("uniform sampler2D s_tex;"));
+        assert_eq!(glsl.uniform(SAMPLER_UNIFORM).unwrap().uniform_type, UniformType::Sampler);
+        assert_eq!(ShaderBinary::parse(VS_OCORNUT_IMGUI.glsl).unwrap().uniform("u_viewProj").unwrap().uniform_type, UniformType::Mat4);
+    }
+
+    #[test]
+    fn every_profile_forms_a_valid_program() {
+        for ((profile, vertex), (_, fragment)) in profiles(&VS_OCORNUT_IMGUI).into_iter().zip(profiles(&FS_OCORNUT_IMGUI)) {
+            let vertex = ShaderBinary::parse(vertex).unwrap();
+            let fragment = ShaderBinary::parse(fragment).unwrap();
+            validate_program(&vertex, &fragment, &[(SAMPLER_UNIFORM, UniformType::Sampler)]).unwrap_or_else(|e| panic!("{}: {}", profile, e));
+        }
+    }
+
+    #[test]
+    fn rejects_broken_blobs_and_programs() {
+        let blob = FS_OCORNUT_IMGUI.glsl;
+        assert!(ShaderBinary::parse(&blob[..blob.len() - 1]).unwrap_err().starts_with("truncated"));
+        assert!(ShaderBinary::parse(b"PNG\x0b\0\0\0\0").unwrap_err().starts_with("not a bgfx shader"));
+        let mut trailing = blob.to_vec();
+        trailing.extend_from_slice(&[0, 0, 0, 0]);
+        assert_eq!(ShaderBinary::parse(&trailing).unwrap_err(), "1 trailing bytes");
+
+        let vertex = ShaderBinary::parse(VS_OCORNUT_IMGUI.glsl).unwrap();
+        let mut fragment = ShaderBinary::parse(blob).unwrap();
+        assert!(validate_program(&fragment, &vertex, &[]).is_err());
+        assert_eq!(validate_program(&vertex, &fragment, &[("s_missing", UniformType::Sampler)]).unwrap_err(), "uniform s_missing is not declared by the shaders");
+        assert_eq!(validate_program(&vertex, &fragment, &[(SAMPLER_UNIFORM, UniformType::Vec4)]).unwrap_err(), "uniform s_tex is a Sampler, expected a Vec4");
+        fragment.hash_in ^= 1;
+        assert!(validate_program(&vertex, &fragment, &[]).unwrap_err().contains("don't match"));
+    }
+}
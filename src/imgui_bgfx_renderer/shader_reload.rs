@@ -0,0 +1,108 @@
+//! Development mode of [`super::Renderer`]: reloads the imgui shaders from disk when they change.
+//!
+//! The watched directory has the layout of `shaders/bin`, one subdirectory per shaderc profile
+//! holding `vs_ocornut_imgui.bin` and `fs_ocornut_imgui.bin`; only the profile of the running
+//! backend is read. Files are polled by modification time and size, blobs that don't parse or
+//! don't form a valid program are reported and the previous program stays in use.
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use bgfx_rs::bgfx::RendererType;
+
+/// Directory of the blobs of `renderer_type` below a `shaders/bin` like directory.
+pub fn profile_dir(renderer_type : RendererType) -> Option<&'static str> {
+    match renderer_type {
+        RendererType::OpenGL | RendererType::Noop => Some("glsl"),
+        RendererType::OpenGLES => Some("essl"),
+        RendererType::Vulkan => Some("spirv"),
+        RendererType::Direct3D9 => Some("dx9"),
+        RendererType::Direct3D11 => Some("dx11"),
+        RendererType::Metal => Some("metal"),
+        _ => None,
+    }
+}
+
+/// Modification time and size of a file, `None` if it can't be read.
+type FileStamp = Option<(SystemTime, u64)>;
+
+fn stamp(path : &Path) -> FileStamp {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+pub struct ShaderWatch {
+    vs_path : PathBuf,
+    fs_path : PathBuf,
+    /// Stamps of the last load attempt, `None` before the first one.
+    stamps : Option<(FileStamp, FileStamp)>,
+    error : Option<String>
+}
+
+impl ShaderWatch {
+    pub fn new(dir : &Path, renderer_type : RendererType) -> Result<ShaderWatch, String> {
+        let profile = profile_dir(renderer_type).ok_or_else(|| format!("no shader profile for renderer type {:?}", renderer_type))?;
+        let dir = dir.join(profile);
+        Ok(ShaderWatch {
+            vs_path: dir.join("vs_ocornut_imgui.bin"),
+            fs_path: dir.join("fs_ocornut_imgui.bin"),
+            stamps: None,
+            error: None
+        })
+    }
+    /// The vertex and fragment shader blobs if either file changed since the last call.
+    /// Blobs are only returned once `validate` accepted them, failures are kept in `error`.
+    pub fn poll<F : FnOnce(&[u8], &[u8]) -> Result<(), String>>(&mut self, validate : F) -> Option<(Vec<u8>, Vec<u8>)> {
+        let stamps = (stamp(&self.vs_path), stamp(&self.fs_path));
+        if self.stamps == Some(stamps) {
+            return None;
+        }
+        self.stamps = Some(stamps);
+        let read = |path : &Path| std::fs::read(path).map_err(|e| format!("{}: {}", path.display(), e));
+        let loaded = read(&self.vs_path).and_then(|vs_code| Ok((vs_code, read(&self.fs_path)?)))
+            .and_then(|(vs_code, fs_code)| validate(&vs_code, &fs_code).map(|()| (vs_code, fs_code)));
+        match loaded {
+            Ok(code) => {
+                self.error = None;
+                Some(code)
+            },
+            Err(e) => {
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+    /// Why the last change couldn't be loaded, cleared by the next successful load.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::imgui_bgfx_renderer::{FS_OCORNUT_IMGUI, VS_OCORNUT_IMGUI};
+
+    #[test]
+    fn reloads_changed_files_and_reports_broken_ones() {
+        let dir = std::env::temp_dir().join(format!("imgui_shader_watch_{}", std::process::id()));
+        let glsl = dir.join("glsl");
+        std::fs::create_dir_all(&glsl).unwrap();
+        let mut watch = ShaderWatch::new(&dir, RendererType::OpenGL).unwrap();
+        let validate = |vs_code : &[u8], _ : &[u8]| if vs_code.is_empty() { Err("empty".to_string()) } else { Ok(()) };
+
+        assert!(watch.poll(validate).is_none());
+        assert!(watch.error().unwrap().contains("vs_ocornut_imgui.bin"));
+
+        std::fs::write(glsl.join("vs_ocornut_imgui.bin"), VS_OCORNUT_IMGUI.glsl).unwrap();
+        std::fs::write(glsl.join("fs_ocornut_imgui.bin"), FS_OCORNUT_IMGUI.glsl).unwrap();
+        assert_eq!(watch.poll(validate), Some((VS_OCORNUT_IMGUI.glsl.to_vec(), FS_OCORNUT_IMGUI.glsl.to_vec())));
+        assert!(watch.error().is_none());
+        // nothing changed
+        assert!(watch.poll(validate).is_none());
+
+        std::fs::write(glsl.join("vs_ocornut_imgui.bin"), b"").unwrap();
+        assert!(watch.poll(validate).is_none());
+        assert_eq!(watch.error(), Some("empty"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
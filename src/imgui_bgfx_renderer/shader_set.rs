@@ -0,0 +1,72 @@
+//! Per-backend blobs of one shader, looked up by the renderer type bgfx runs on.
+use bgfx_rs::bgfx::RendererType;
+use crate::error::{Error, Result};
+
+/// The compiled variants of one shader, one per shaderc profile. Profiles whose backend
+/// feature is disabled aren't embedded and are `None`.
+#[derive(Clone, Copy, Debug)]
+pub struct ShaderSet {
+    /// Always embedded, the Noop backend uses it too.
+    pub glsl : &'static [u8],
+    pub essl : Option<&'static [u8]>,
+    pub spv : Option<&'static [u8]>,
+    pub dx9 : Option<&'static [u8]>,
+    pub dx11 : Option<&'static [u8]>,
+    pub mtl : Option<&'static [u8]>
+}
+
+/// Builds a [`ShaderSet`] from the constants of a shader module, named in the order
+/// `_GLSL`, `_ESSL`, `_SPV`, `_DX9`, `_DX11`, `_MTL`.
+macro_rules! shader_set {
+    ($module:ident::{$glsl:ident, $essl:ident, $spv:ident, $dx9:ident, $dx11:ident, $mtl:ident}) => {
+        $crate::imgui_bgfx_renderer::ShaderSet {
+            glsl: $module::$glsl,
+            essl: $module::$essl,
+            spv: $module::$spv,
+            dx9: $module::$dx9,
+            dx11: $module::$dx11,
+            mtl: $module::$mtl
+        }
+    };
+}
+pub(crate) use shader_set;
+
+impl ShaderSet {
+    /// Blob for `renderer_type`, `None` if there is none or its backend feature is disabled.
+    pub fn get(&self, renderer_type : RendererType) -> Option<&'static [u8]> {
+        match renderer_type {
+            RendererType::Direct3D9 => self.dx9,
+            RendererType::Direct3D11 => self.dx11,
+            #[cfg(feature = "opengl")]
+            RendererType::OpenGL => Some(self.glsl),
+            RendererType::Metal => self.mtl,
+            RendererType::OpenGLES => self.essl,
+            RendererType::Vulkan => self.spv,
+            // the Noop backend never compiles shaders, it only needs a well-formed blob
+            RendererType::Noop => Some(self.glsl),
+            _ => None,
+        }
+    }
+    /// Same as [`ShaderSet::get`], failing with [`Error::UnsupportedRenderer`].
+    pub fn code(&self, renderer_type : RendererType) -> Result<&'static [u8]> {
+        self.get(renderer_type).ok_or(Error::UnsupportedRenderer(renderer_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::imgui_bgfx_renderer::{vs_ocornut_imgui, FS_OCORNUT_IMGUI, VS_OCORNUT_IMGUI};
+
+    #[test]
+    fn looks_up_blobs_by_renderer_type() {
+        assert_eq!(VS_OCORNUT_IMGUI.get(RendererType::Noop), Some(vs_ocornut_imgui::VS_OCORNUT_IMGUI_GLSL));
+        assert_eq!(VS_OCORNUT_IMGUI.get(RendererType::Vulkan), vs_ocornut_imgui::VS_OCORNUT_IMGUI_SPV);
+        assert_eq!(VS_OCORNUT_IMGUI.spv.is_some(), cfg!(feature = "vulkan"));
+        #[cfg(feature = "direct3d11")]
+        assert_eq!(FS_OCORNUT_IMGUI.code(RendererType::Direct3D11).unwrap(), FS_OCORNUT_IMGUI.dx11.unwrap());
+        #[cfg(not(feature = "direct3d11"))]
+        assert!(matches!(FS_OCORNUT_IMGUI.code(RendererType::Direct3D11), Err(Error::UnsupportedRenderer(RendererType::Direct3D11))));
+        assert!(matches!(FS_OCORNUT_IMGUI.code(RendererType::Direct3D12), Err(Error::UnsupportedRenderer(RendererType::Direct3D12))));
+    }
+}
@@ -0,0 +1,68 @@
+//! Clipboard backends for imgui's copy and paste.
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::rc::Rc;
+use glfw::Context;
+
+/// System clipboard through GLFW.
+///
+/// imgui keeps the backend for the lifetime of its context, so this holds the raw window
+/// pointer instead of a borrow of the window.
+pub struct GlfwClipboard {
+    window : *mut glfw::ffi::GLFWwindow
+}
+
+impl GlfwClipboard {
+    /// # Safety
+    /// `window` has to outlive the imgui context the clipboard is installed in.
+    pub unsafe fn new(window : &glfw::Window) -> GlfwClipboard {
+        GlfwClipboard { window: window.window_ptr() }
+    }
+}
+
+impl imgui::ClipboardBackend for GlfwClipboard {
+    fn get(&mut self) -> Option<String> {
+        unsafe {
+            let text = glfw::ffi::glfwGetClipboardString(self.window);
+            if text.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(text).to_string_lossy().into_owned())
+            }
+        }
+    }
+    fn set(&mut self, value : &str) {
+        // interior nul bytes can't cross the C API, cut the text there
+        let value = value.split('\0').next().unwrap_or_default();
+        let text = CString::new(value).unwrap();
+        unsafe { glfw::ffi::glfwSetClipboardString(self.window, text.as_ptr()); }
+    }
+}
+
+/// Clipboard that only lives in memory, for headless runs and tests.
+/// Clones share their contents.
+#[derive(Clone, Default)]
+pub struct MemoryClipboard {
+    contents : Rc<RefCell<Option<String>>>
+}
+
+impl MemoryClipboard {
+    pub fn new() -> MemoryClipboard {
+        MemoryClipboard::default()
+    }
+    pub fn contents(&self) -> Option<String> {
+        self.contents.borrow().clone()
+    }
+    pub fn set_contents(&self, contents : &str) {
+        *self.contents.borrow_mut() = Some(contents.to_string());
+    }
+}
+
+impl imgui::ClipboardBackend for MemoryClipboard {
+    fn get(&mut self) -> Option<String> {
+        self.contents()
+    }
+    fn set(&mut self, value : &str) {
+        self.set_contents(value);
+    }
+}
@@ -0,0 +1,105 @@
+//! OS mouse cursor shapes requested by imgui.
+use glfw::StandardCursor;
+use imgui::MouseCursor;
+
+/// Closest GLFW standard cursor, GLFW has no diagonal, four-way or "not allowed" shapes.
+pub fn standard_cursor(cursor : MouseCursor) -> StandardCursor {
+    match cursor {
+        MouseCursor::Arrow => StandardCursor::Arrow,
+        MouseCursor::TextInput => StandardCursor::IBeam,
+        MouseCursor::ResizeNS => StandardCursor::VResize,
+        MouseCursor::ResizeEW => StandardCursor::HResize,
+        MouseCursor::Hand => StandardCursor::Hand,
+        MouseCursor::ResizeAll | MouseCursor::ResizeNESW | MouseCursor::ResizeNWSE | MouseCursor::NotAllowed => StandardCursor::Arrow,
+    }
+}
+
+/// What the window should show, `None` hides the OS cursor.
+pub fn requested_cursor(io : &imgui::Io, cursor : Option<MouseCursor>) -> Option<StandardCursor> {
+    if io.mouse_draw_cursor {
+        // imgui draws the cursor itself
+        return None;
+    }
+    cursor.map(standard_cursor)
+}
+
+/// Every cursor `standard_cursor` maps to.
+const STANDARD_CURSORS: [StandardCursor; 5] = [
+    StandardCursor::Arrow, StandardCursor::IBeam, StandardCursor::Hand, StandardCursor::HResize, StandardCursor::VResize
+];
+
+/// Standard cursors, created the first time they are shown.
+///
+/// `glfw::Window::set_cursor` takes ownership of the cursor it shows, so the
+/// slot of the shown cursor is empty until the window hands it back.
+#[derive(Default)]
+pub struct MouseCursors {
+    cursors : [Option<glfw::Cursor>; STANDARD_CURSORS.len()],
+    // the cursor owned by the window, it stays there while the cursor is hidden
+    window_cursor : Option<StandardCursor>,
+    // `None` until the first update
+    shown : Option<Option<StandardCursor>>
+}
+
+fn slot(cursor : StandardCursor) -> usize {
+    STANDARD_CURSORS.iter().position(|standard| *standard == cursor).unwrap()
+}
+
+impl MouseCursors {
+    /// `cursor` is one returned by `standard_cursor`, or `None` to hide it.
+    pub fn update(&mut self, window : &mut glfw::Window, cursor : Option<StandardCursor>) {
+        if self.shown == Some(cursor) {
+            return;
+        }
+        match cursor {
+            Some(standard) => {
+                if self.window_cursor != Some(standard) {
+                    let glfw_cursor = self.cursors[slot(standard)].take().unwrap_or_else(|| glfw::Cursor::standard(standard));
+                    let previous = window.set_cursor(Some(glfw_cursor));
+                    if let (Some(previous), Some(previous_standard)) = (previous, self.window_cursor) {
+                        self.cursors[slot(previous_standard)] = Some(previous);
+                    }
+                    self.window_cursor = Some(standard);
+                }
+                window.set_cursor_mode(glfw::CursorMode::Normal);
+            },
+            None => window.set_cursor_mode(glfw::CursorMode::Hidden),
+        }
+        self.shown = Some(cursor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::lock_globals;
+
+    #[test]
+    fn maps_every_imgui_cursor() {
+        let expected = [
+            (MouseCursor::Arrow, StandardCursor::Arrow),
+            (MouseCursor::TextInput, StandardCursor::IBeam),
+            (MouseCursor::ResizeAll, StandardCursor::Arrow),
+            (MouseCursor::ResizeNS, StandardCursor::VResize),
+            (MouseCursor::ResizeEW, StandardCursor::HResize),
+            (MouseCursor::ResizeNESW, StandardCursor::Arrow),
+            (MouseCursor::ResizeNWSE, StandardCursor::Arrow),
+            (MouseCursor::Hand, StandardCursor::Hand),
+            (MouseCursor::NotAllowed, StandardCursor::Arrow),
+        ];
+        assert_eq!(expected.len(), MouseCursor::VARIANTS.len());
+        for (cursor, standard) in expected {
+            assert_eq!(standard_cursor(cursor), standard);
+        }
+    }
+
+    #[test]
+    fn hides_the_os_cursor_when_imgui_draws_it() {
+        let _lock = lock_globals();
+        let mut imgui_context = imgui::Context::create();
+        assert_eq!(requested_cursor(imgui_context.io(), Some(MouseCursor::TextInput)), Some(StandardCursor::IBeam));
+        assert_eq!(requested_cursor(imgui_context.io(), None), None);
+        imgui_context.io_mut().mouse_draw_cursor = true;
+        assert_eq!(requested_cursor(imgui_context.io(), Some(MouseCursor::TextInput)), None);
+    }
+}
@@ -0,0 +1,139 @@
+//! Translation of GLFW keys into imgui's keyboard state.
+//!
+//! imgui 0.8 has no key event API yet, the backend sets `io.keys_down` indexed by its own key
+//! codes and tells imgui through `io.key_map` which of those codes are the keys it needs for
+//! widgets and keyboard navigation. GLFW key codes are used as the `keys_down` indices.
+use glfw::Key;
+
+/// What a GLFW key changes in `imgui::Io`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyTranslation {
+    /// Index into `io.keys_down`.
+    KeysDown(usize),
+    Shift,
+    Ctrl,
+    Alt,
+    Super,
+    Ignored
+}
+
+/// Number of entries in `io.keys_down`.
+pub const KEYS_DOWN_COUNT: usize = 512;
+
+/// Keys imgui widgets and keyboard navigation look up through `io.key_map`.
+pub const KEY_MAP: [(imgui::Key, Key); imgui::Key::COUNT] = [
+    (imgui::Key::Tab, Key::Tab),
+    (imgui::Key::LeftArrow, Key::Left),
+    (imgui::Key::RightArrow, Key::Right),
+    (imgui::Key::UpArrow, Key::Up),
+    (imgui::Key::DownArrow, Key::Down),
+    (imgui::Key::PageUp, Key::PageUp),
+    (imgui::Key::PageDown, Key::PageDown),
+    (imgui::Key::Home, Key::Home),
+    (imgui::Key::End, Key::End),
+    (imgui::Key::Insert, Key::Insert),
+    (imgui::Key::Delete, Key::Delete),
+    (imgui::Key::Backspace, Key::Backspace),
+    (imgui::Key::Space, Key::Space),
+    (imgui::Key::Enter, Key::Enter),
+    (imgui::Key::Escape, Key::Escape),
+    (imgui::Key::KeyPadEnter, Key::KpEnter),
+    (imgui::Key::A, Key::A),
+    (imgui::Key::C, Key::C),
+    (imgui::Key::V, Key::V),
+    (imgui::Key::X, Key::X),
+    (imgui::Key::Y, Key::Y),
+    (imgui::Key::Z, Key::Z),
+];
+
+/// Every `glfw::Key`.
+pub const ALL_KEYS: [Key; 121] = [
+    Key::Space, Key::Apostrophe, Key::Comma, Key::Minus, Key::Period, Key::Slash,
+    Key::Num0, Key::Num1, Key::Num2, Key::Num3, Key::Num4, Key::Num5, Key::Num6, Key::Num7, Key::Num8, Key::Num9,
+    Key::Semicolon, Key::Equal,
+    Key::A, Key::B, Key::C, Key::D, Key::E, Key::F, Key::G, Key::H, Key::I, Key::J, Key::K, Key::L, Key::M,
+    Key::N, Key::O, Key::P, Key::Q, Key::R, Key::S, Key::T, Key::U, Key::V, Key::W, Key::X, Key::Y, Key::Z,
+    Key::LeftBracket, Key::Backslash, Key::RightBracket, Key::GraveAccent, Key::World1, Key::World2,
+    Key::Escape, Key::Enter, Key::Tab, Key::Backspace, Key::Insert, Key::Delete,
+    Key::Right, Key::Left, Key::Down, Key::Up, Key::PageUp, Key::PageDown, Key::Home, Key::End,
+    Key::CapsLock, Key::ScrollLock, Key::NumLock, Key::PrintScreen, Key::Pause,
+    Key::F1, Key::F2, Key::F3, Key::F4, Key::F5, Key::F6, Key::F7, Key::F8, Key::F9, Key::F10, Key::F11, Key::F12, Key::F13,
+    Key::F14, Key::F15, Key::F16, Key::F17, Key::F18, Key::F19, Key::F20, Key::F21, Key::F22, Key::F23, Key::F24, Key::F25,
+    Key::Kp0, Key::Kp1, Key::Kp2, Key::Kp3, Key::Kp4, Key::Kp5, Key::Kp6, Key::Kp7, Key::Kp8, Key::Kp9,
+    Key::KpDecimal, Key::KpDivide, Key::KpMultiply, Key::KpSubtract, Key::KpAdd, Key::KpEnter, Key::KpEqual,
+    Key::LeftShift, Key::LeftControl, Key::LeftAlt, Key::LeftSuper, Key::RightShift, Key::RightControl, Key::RightAlt, Key::RightSuper,
+    Key::Menu, Key::Unknown
+];
+
+fn keys_down(key : Key) -> KeyTranslation {
+    let index = key as i32;
+    if (0..KEYS_DOWN_COUNT as i32).contains(&index) {
+        KeyTranslation::KeysDown(index as usize)
+    } else {
+        KeyTranslation::Ignored
+    }
+}
+
+/// No wildcard arm on purpose, new GLFW keys have to be sorted in here explicitly.
+pub fn translate_key(key : Key) -> KeyTranslation {
+    match key {
+        Key::LeftShift | Key::RightShift => KeyTranslation::Shift,
+        Key::LeftControl | Key::RightControl => KeyTranslation::Ctrl,
+        Key::LeftAlt | Key::RightAlt => KeyTranslation::Alt,
+        Key::LeftSuper | Key::RightSuper => KeyTranslation::Super,
+        // no stable code, several physical keys report it
+        Key::Unknown => KeyTranslation::Ignored,
+        Key::Space | Key::Apostrophe | Key::Comma | Key::Minus | Key::Period | Key::Slash |
+        Key::Num0 | Key::Num1 | Key::Num2 | Key::Num3 | Key::Num4 | Key::Num5 | Key::Num6 | Key::Num7 | Key::Num8 | Key::Num9 |
+        Key::Semicolon | Key::Equal |
+        Key::A | Key::B | Key::C | Key::D | Key::E | Key::F | Key::G | Key::H | Key::I | Key::J | Key::K | Key::L | Key::M |
+        Key::N | Key::O | Key::P | Key::Q | Key::R | Key::S | Key::T | Key::U | Key::V | Key::W | Key::X | Key::Y | Key::Z |
+        Key::LeftBracket | Key::Backslash | Key::RightBracket | Key::GraveAccent | Key::World1 | Key::World2 |
+        Key::Escape | Key::Enter | Key::Tab | Key::Backspace | Key::Insert | Key::Delete |
+        Key::Right | Key::Left | Key::Down | Key::Up | Key::PageUp | Key::PageDown | Key::Home | Key::End |
+        Key::CapsLock | Key::ScrollLock | Key::NumLock | Key::PrintScreen | Key::Pause |
+        Key::F1 | Key::F2 | Key::F3 | Key::F4 | Key::F5 | Key::F6 | Key::F7 | Key::F8 | Key::F9 | Key::F10 | Key::F11 | Key::F12 | Key::F13 |
+        Key::F14 | Key::F15 | Key::F16 | Key::F17 | Key::F18 | Key::F19 | Key::F20 | Key::F21 | Key::F22 | Key::F23 | Key::F24 | Key::F25 |
+        Key::Kp0 | Key::Kp1 | Key::Kp2 | Key::Kp3 | Key::Kp4 | Key::Kp5 | Key::Kp6 | Key::Kp7 | Key::Kp8 | Key::Kp9 |
+        Key::KpDecimal | Key::KpDivide | Key::KpMultiply | Key::KpSubtract | Key::KpAdd | Key::KpEnter | Key::KpEqual |
+        Key::Menu => keys_down(key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use super::*;
+
+    /// Modifiers only set imgui's modifier flags and `Unknown` is ignored, none of them has a `keys_down` slot.
+    const WITHOUT_INDEX: [Key; 9] = [
+        Key::LeftShift, Key::LeftControl, Key::LeftAlt, Key::LeftSuper, Key::RightShift, Key::RightControl, Key::RightAlt, Key::RightSuper,
+        Key::Unknown
+    ];
+
+    #[test]
+    fn every_key_maps_or_is_ignored() {
+        let mut indices = HashMap::new();
+        for key in ALL_KEYS.iter().copied() {
+            match translate_key(key) {
+                KeyTranslation::KeysDown(index) => {
+                    assert!(index < KEYS_DOWN_COUNT, "{:?} is out of bounds", key);
+                    if let Some(other) = indices.insert(index, key) {
+                        panic!("{:?} and {:?} share index {}", key, other, index);
+                    }
+                },
+                KeyTranslation::Ignored => assert_eq!(key, Key::Unknown),
+                _ => assert!(WITHOUT_INDEX.contains(&key), "{:?} has no index", key),
+            }
+        }
+        assert_eq!(indices.len(), ALL_KEYS.len() - WITHOUT_INDEX.len());
+    }
+
+    #[test]
+    fn key_map_covers_every_imgui_key() {
+        for imgui_key in imgui::Key::VARIANTS.iter() {
+            let (_, key) = KEY_MAP.iter().find(|(mapped, _)| mapped == imgui_key).unwrap();
+            assert!(matches!(translate_key(*key), KeyTranslation::KeysDown(_)), "{:?} is not tracked", key);
+        }
+    }
+}
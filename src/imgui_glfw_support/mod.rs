@@ -1,75 +1,425 @@
+pub mod clipboard;
+pub mod cursor;
+pub mod keys;
+pub mod recording;
+
+use std::collections::VecDeque;
+use std::time::Instant;
+use cursor::MouseCursors;
+use keys::{translate_key, KeyTranslation, KEY_MAP};
+use crate::error::{Error, Result};
+
+/// GLFW buttons in the order of `io.mouse_down`: left, right, middle, back and forward.
+pub const IMGUI_MOUSE_BUTTONS: [glfw::MouseButton; 5] = [
+    glfw::MouseButtonLeft, glfw::MouseButtonRight, glfw::MouseButtonMiddle, glfw::MouseButton::Button4, glfw::MouseButton::Button5
+];
+
+/// imgui's "no mouse" position.
+const MOUSE_POSITION_UNKNOWN: [f32; 2] = [-f32::MAX, -f32::MAX];
+
+/// Registered as the imgui context's platform name, so a second backend can be detected.
+const PLATFORM_NAME: &str = "imgui_glfw_support";
+
+/// Mouse events in arrival order, see `GlfwPlatform::update_mouse`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MouseInput {
+    Move([f32; 2]),
+    Leave,
+    Button(usize, bool)
+}
+
+/// How much bigger the UI has to be drawn on the window's monitor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DisplayScale {
+    /// GLFW's content scale, the DPI setting of the monitor.
+    pub content : f32,
+    /// Framebuffer pixels per window coordinate, above 1 on e.g. retina displays.
+    pub framebuffer : f32
+}
+
+impl Default for DisplayScale {
+    fn default() -> Self {
+        DisplayScale { content: 1.0, framebuffer: 1.0 }
+    }
+}
+
+impl DisplayScale {
+    pub fn of(window : &glfw::Window) -> DisplayScale {
+        let (content, _) = window.get_content_scale();
+        DisplayScale::from_sizes(window.get_size(), window.get_framebuffer_size(), content)
+    }
+    pub fn from_sizes(window_size : (i32, i32), framebuffer_size : (i32, i32), content : f32) -> DisplayScale {
+        // minimized windows report zero sizes
+        let framebuffer = if window_size.0 > 0 && framebuffer_size.0 > 0 {
+            framebuffer_size.0 as f32 / window_size.0 as f32
+        } else {
+            1.0
+        };
+        DisplayScale { content: if content > 0.0 { content } else { 1.0 }, framebuffer }
+    }
+}
+
 pub struct GlfwPlatform {
-    mouse_wheel : f32,
-    last_character : char
+    mouse_position : [f32; 2],
+    mouse_down : [bool; 5],
+    mouse_queue : VecDeque<MouseInput>,
+    // horizontal and vertical scroll summed over the frame
+    mouse_wheel : [f32; 2],
+    // everything typed since the last reset, several characters can arrive in one frame
+    characters : Vec<char>,
+    mouse_cursors : MouseCursors,
+    display_scale_changed : bool,
+    last_frame : Instant
 }
 
 impl GlfwPlatform {
-    pub fn init(imgui_context : &mut imgui::Context) -> GlfwPlatform {
-        let mut io = imgui_context.io_mut();
-        io.key_map[imgui::Key::Tab as usize] = glfw::Key::Tab as u32;
-        io.key_map[imgui::Key::LeftArrow as usize] = glfw::Key::Left as u32;
-        io.key_map[imgui::Key::RightArrow as usize] = glfw::Key::Right as u32;
-        io.key_map[imgui::Key::UpArrow as usize] = glfw::Key::Up as u32;
-        io.key_map[imgui::Key::DownArrow as usize] = glfw::Key::Down as u32;
-        io.key_map[imgui::Key::PageUp as usize] = glfw::Key::PageUp as u32;
-        io.key_map[imgui::Key::PageDown as usize] = glfw::Key::PageDown as u32;
-        io.key_map[imgui::Key::Home as usize] = glfw::Key::Home as u32;
-        io.key_map[imgui::Key::End as usize] = glfw::Key::End as u32;
-        io.key_map[imgui::Key::Insert as usize] = glfw::Key::Insert as u32;
-        io.key_map[imgui::Key::Delete as usize] = glfw::Key::Delete as u32;
-        io.key_map[imgui::Key::Backspace as usize] = glfw::Key::Backspace as u32;
-        io.key_map[imgui::Key::Space as usize] = glfw::Key::Space as u32;
-        io.key_map[imgui::Key::Enter as usize] = glfw::Key::Enter as u32;
-        io.key_map[imgui::Key::Escape as usize] = glfw::Key::Escape as u32;
-        io.key_map[imgui::Key::KeyPadEnter as usize] = glfw::Key::KpEnter as u32;
-        io.key_map[imgui::Key::A as usize] = glfw::Key::A as u32;
-        io.key_map[imgui::Key::C as usize] = glfw::Key::C as u32;
-        io.key_map[imgui::Key::V as usize] = glfw::Key::V as u32;
-        io.key_map[imgui::Key::X as usize] = glfw::Key::X as u32;
-        io.key_map[imgui::Key::Y as usize] = glfw::Key::Y as u32;
-        io.key_map[imgui::Key::Z as usize] = glfw::Key::Z as u32;
-        GlfwPlatform { mouse_wheel: 0.0, last_character: '\0' }
-    }
-    pub fn translate_glfw_mouse_buttons_for_imgui(glfw_window : &glfw::Window) -> u8 {
-        let mut mouse_buttons: u8 = 0;
-        if glfw_window.get_mouse_button(glfw::MouseButtonLeft) == glfw::Action::Press { mouse_buttons |= 0x01 << 0; }
-        if glfw_window.get_mouse_button(glfw::MouseButtonRight) == glfw::Action::Press { mouse_buttons |= 0x01 << 1; }
-        if glfw_window.get_mouse_button(glfw::MouseButtonMiddle) == glfw::Action::Press { mouse_buttons |= 0x01 << 2; }
-        return mouse_buttons;
-    }
-    pub fn reset(&mut self){
-        self.mouse_wheel = 0.0;
-        self.last_character = '\0';
+    /// Sets up the key map and installs `clipboard` for imgui's copy and paste.
+    /// Fails if `imgui_context` already has a platform backend.
+    pub fn init<C : imgui::ClipboardBackend>(imgui_context : &mut imgui::Context, clipboard : C) -> Result<GlfwPlatform> {
+        if let Some(name) = imgui_context.platform_name() {
+            return Err(Error::PlatformAlreadyInitialized(name.to_string()));
+        }
+        imgui_context.set_platform_name(Some(PLATFORM_NAME.to_string()));
+        imgui_context.set_clipboard_backend(clipboard);
+        let io = imgui_context.io_mut();
+        io.backend_flags |= imgui::BackendFlags::HAS_MOUSE_CURSORS;
+        for (imgui_key, key) in KEY_MAP.iter() {
+            io.key_map[*imgui_key as usize] = *key as u32;
+        }
+        Ok(GlfwPlatform {
+            mouse_position: MOUSE_POSITION_UNKNOWN,
+            mouse_down: [false; 5],
+            mouse_queue: VecDeque::new(),
+            mouse_wheel: [0.0, 0.0],
+            characters: Vec::new(),
+            mouse_cursors: MouseCursors::default(),
+            display_scale_changed: false,
+            last_frame: Instant::now()
+        })
+    }
+    /// Index of `button` in `io.mouse_down`, `None` for buttons imgui doesn't track.
+    pub fn mouse_button_index(button : glfw::MouseButton) -> Option<usize> {
+        IMGUI_MOUSE_BUTTONS.iter().position(|b| *b == button)
+    }
+    /// Hands the input gathered since the last frame, the display size and the frame time to imgui.
+    ///
+    /// `display_size` is in window coordinates like the cursor position, the renderer
+    /// scales up to framebuffer pixels with `display_framebuffer_scale`.
+    pub fn prepare_frame(&mut self, io : &mut imgui::Io, window : &glfw::Window) {
+        let (width, height) = window.get_size();
+        let scale = DisplayScale::from_sizes((width, height), window.get_framebuffer_size(), 1.0);
+        let now = Instant::now();
+        let delta_time = (now - self.last_frame).as_secs_f32();
+        self.last_frame = now;
+        self.prepare_frame_with(io, [width as f32, height as f32], scale.framebuffer, delta_time);
+    }
+    /// `prepare_frame` for runs without a window, e.g. headless or with recorded input.
+    pub fn prepare_frame_with(&mut self, io : &mut imgui::Io, display_size : [f32; 2], framebuffer_scale : f32, delta_time : f32) {
+        self.update_mouse(io);
+        io.mouse_wheel_h = self.mouse_wheel[0];
+        io.mouse_wheel = self.mouse_wheel[1];
+        for character in &self.characters {
+            io.add_input_character(*character);
+        }
+        io.display_size = display_size;
+        io.display_framebuffer_scale = [framebuffer_scale, framebuffer_scale];
+        // imgui asserts on a zero time step
+        io.delta_time = delta_time.max(1.0e-6);
+        self.reset();
+    }
+    /// Whether the window moved to a monitor with a different scale since the last call,
+    /// the fonts need to be rebuilt for `DisplayScale::of` then.
+    pub fn take_display_scale_change(&mut self) -> bool {
+        std::mem::take(&mut self.display_scale_changed)
+    }
+    fn reset(&mut self){
+        self.mouse_wheel = [0.0, 0.0];
+        self.characters.clear();
     }
     pub fn handle_event(&mut self, imgui_context: &mut imgui::Context, event : &glfw::WindowEvent){
         if let glfw::WindowEvent::Key(key, _, action, _) = event {
             GlfwPlatform::handle_key_event(imgui_context, *key, *action);
-        } else if let glfw::WindowEvent::Scroll(_, scroll_y) = event {
-            self.mouse_wheel = *scroll_y as f32;
+        } else if let glfw::WindowEvent::Scroll(scroll_x, scroll_y) = event {
+            self.mouse_wheel[0] += *scroll_x as f32;
+            self.mouse_wheel[1] += *scroll_y as f32;
         } else if let glfw::WindowEvent::Char(character) = event {
-           self.last_character = *character;
+           self.characters.push(*character);
+        } else if let glfw::WindowEvent::CursorPos(x, y) = event {
+            self.mouse_queue.push_back(MouseInput::Move([*x as f32, *y as f32]));
+        } else if let glfw::WindowEvent::CursorEnter(false) = event {
+            self.mouse_queue.push_back(MouseInput::Leave);
+        } else if let glfw::WindowEvent::MouseButton(button, action, _) = event {
+            if let Some(index) = GlfwPlatform::mouse_button_index(*button) {
+                self.mouse_queue.push_back(MouseInput::Button(index, *action != glfw::Action::Release));
+            }
+        } else if let glfw::WindowEvent::ContentScale(_, _) = event {
+            self.display_scale_changed = true;
+        } else if let glfw::WindowEvent::Focus(false) = event {
+            // the release events go to whichever window gets the focus
+            for index in 0..self.mouse_down.len() {
+                self.mouse_queue.push_back(MouseInput::Button(index, false));
+            }
+        }
+    }
+    /// Applies the queued mouse events to `io`.
+    ///
+    /// imgui only looks at the button state once per frame, so a button changes at most
+    /// once per frame: a press and release within the same frame show up as a click over
+    /// two frames. Events after a deferred transition wait with it to keep their order.
+    fn update_mouse(&mut self, io : &mut imgui::Io) {
+        let mut changed = [false; 5];
+        while let Some(input) = self.mouse_queue.front() {
+            match *input {
+                MouseInput::Move(position) => self.mouse_position = position,
+                MouseInput::Leave => self.mouse_position = MOUSE_POSITION_UNKNOWN,
+                MouseInput::Button(index, down) => {
+                    if self.mouse_down[index] != down {
+                        if changed[index] {
+                            break;
+                        }
+                        changed[index] = true;
+                        self.mouse_down[index] = down;
+                    }
+                }
+            }
+            self.mouse_queue.pop_front();
         }
+        io.mouse_pos = self.mouse_position;
+        io.mouse_down = self.mouse_down;
     }
     pub fn handle_key_event(imgui_context : &mut imgui::Context, key : glfw::Key, action : glfw::Action){
         if action == glfw::Action::Press || action == glfw::Action::Release {
-            let mut io = imgui_context.io_mut();
-            if key == glfw::Key::LeftShift || key == glfw::Key::RightShift {
-                io.key_shift = action == glfw::Action::Press;
-            } else if key == glfw::Key::LeftControl || key == glfw::Key::RightControl {
-                io.key_ctrl = action == glfw::Action::Press;
-            } else if key == glfw::Key::LeftAlt || key == glfw::Key::RightAlt {
-                io.key_alt = action == glfw::Action::Press;
-            } else if key == glfw::Key::LeftSuper || key == glfw::Key::RightSuper {
-                io.key_super = action == glfw::Action::Press;
-            } else if key != glfw::Key::Unknown {
-                io.keys_down[key as usize] = action == glfw::Action::Press;
+            let io = imgui_context.io_mut();
+            let pressed = action == glfw::Action::Press;
+            match translate_key(key) {
+                KeyTranslation::KeysDown(index) => io.keys_down[index] = pressed,
+                KeyTranslation::Shift => io.key_shift = pressed,
+                KeyTranslation::Ctrl => io.key_ctrl = pressed,
+                KeyTranslation::Alt => io.key_alt = pressed,
+                KeyTranslation::Super => io.key_super = pressed,
+                KeyTranslation::Ignored => {}
             }
         }
     }
-    pub fn get_mouse_wheel(&self) -> f32 {
-        return self.mouse_wheel;
+    /// Shows the cursor shape imgui asks for, call after building the UI and before rendering it.
+    pub fn prepare_render(&mut self, ui : &imgui::Ui, window : &mut glfw::Window) {
+        let io = ui.io();
+        if io.config_flags.contains(imgui::ConfigFlags::NO_MOUSE_CURSOR_CHANGE) {
+            return;
+        }
+        self.mouse_cursors.update(window, cursor::requested_cursor(io, ui.mouse_cursor()));
     }
-    pub fn get_last_character(&self) -> char {
-        return self.last_character;
+    /// Horizontal and vertical scroll since the last reset.
+    pub fn get_mouse_wheel(&self) -> [f32; 2] {
+        self.mouse_wheel
     }
-}
\ No newline at end of file
+    pub fn get_characters(&self) -> &[char] {
+        &self.characters
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::clipboard::MemoryClipboard;
+    use crate::test_support::{fixed_window, lock_globals, with_headless_app};
+
+    fn init_platform() -> (imgui::Context, GlfwPlatform) {
+        let mut imgui_context = imgui::Context::create();
+        let platform = GlfwPlatform::init(&mut imgui_context, MemoryClipboard::new()).unwrap();
+        (imgui_context, platform)
+    }
+
+    #[test]
+    fn refuses_a_second_platform_backend() {
+        let _lock = lock_globals();
+        let (mut imgui_context, _platform) = init_platform();
+        match GlfwPlatform::init(&mut imgui_context, MemoryClipboard::new()) {
+            Err(Error::PlatformAlreadyInitialized(name)) => assert_eq!(name, PLATFORM_NAME),
+            _ => panic!("expected PlatformAlreadyInitialized"),
+        }
+    }
+
+    #[test]
+    fn queues_every_character_of_a_frame() {
+        let _lock = lock_globals();
+        let (mut imgui_context, mut platform) = init_platform();
+        for character in "héllo, 世界".chars() {
+            platform.handle_event(&mut imgui_context, &glfw::WindowEvent::Char(character));
+        }
+        assert_eq!(platform.get_characters().iter().collect::<String>(), "héllo, 世界");
+        platform.prepare_frame_with(imgui_context.io_mut(), [640.0, 480.0], 1.0, 1.0 / 60.0);
+        assert!(platform.get_characters().is_empty());
+    }
+
+    #[test]
+    fn accumulates_scrolling_within_a_frame() {
+        let _lock = lock_globals();
+        let (mut imgui_context, mut platform) = init_platform();
+        for event in [glfw::WindowEvent::Scroll(0.0, 1.0), glfw::WindowEvent::Scroll(-0.5, 1.0), glfw::WindowEvent::Scroll(-1.0, 0.5)] {
+            platform.handle_event(&mut imgui_context, &event);
+        }
+        assert_eq!(platform.get_mouse_wheel(), [-1.5, 2.5]);
+        platform.prepare_frame_with(imgui_context.io_mut(), [640.0, 480.0], 1.0, 1.0 / 60.0);
+        assert_eq!([imgui_context.io().mouse_wheel_h, imgui_context.io().mouse_wheel], [-1.5, 2.5]);
+        assert_eq!(platform.get_mouse_wheel(), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn derives_the_display_scale_from_window_and_framebuffer() {
+        assert_eq!(DisplayScale::from_sizes((1280, 720), (2560, 1440), 2.0), DisplayScale { content: 2.0, framebuffer: 2.0 });
+        // scaled desktops on Windows and X11 keep window and framebuffer the same size
+        assert_eq!(DisplayScale::from_sizes((1920, 1080), (1920, 1080), 1.5), DisplayScale { content: 1.5, framebuffer: 1.0 });
+        assert_eq!(DisplayScale::from_sizes((0, 0), (0, 0), 0.0), DisplayScale::default());
+
+        let _lock = lock_globals();
+        let (mut imgui_context, mut platform) = init_platform();
+        platform.handle_event(&mut imgui_context, &glfw::WindowEvent::ContentScale(2.0, 2.0));
+        platform.prepare_frame_with(imgui_context.io_mut(), [1280.0, 720.0], 2.0, 1.0 / 60.0);
+        assert_eq!(imgui_context.io().display_size, [1280.0, 720.0]);
+        assert_eq!(imgui_context.io().display_framebuffer_scale, [2.0, 2.0]);
+        assert!(platform.take_display_scale_change());
+        assert!(!platform.take_display_scale_change());
+    }
+
+    #[test]
+    fn forwards_back_and_forward_buttons() {
+        assert_eq!(IMGUI_MOUSE_BUTTONS.len(), imgui::MouseButton::VARIANTS.len());
+        assert_eq!(GlfwPlatform::mouse_button_index(glfw::MouseButton::Button4), Some(imgui::MouseButton::Extra1 as usize));
+        assert_eq!(GlfwPlatform::mouse_button_index(glfw::MouseButton::Button5), Some(imgui::MouseButton::Extra2 as usize));
+        assert_eq!(GlfwPlatform::mouse_button_index(glfw::MouseButton::Button8), None);
+    }
+
+    fn button(button : glfw::MouseButton, action : glfw::Action) -> glfw::WindowEvent {
+        glfw::WindowEvent::MouseButton(button, action, glfw::Modifiers::empty())
+    }
+
+    #[test]
+    fn spreads_clicks_within_a_frame_over_two_frames() {
+        let _lock = lock_globals();
+        let (mut imgui_context, mut platform) = init_platform();
+        let events = [
+            glfw::WindowEvent::CursorPos(10.0, 20.0),
+            button(glfw::MouseButtonLeft, glfw::Action::Press),
+            button(glfw::MouseButtonRight, glfw::Action::Press),
+            button(glfw::MouseButtonLeft, glfw::Action::Release),
+            glfw::WindowEvent::CursorPos(30.0, 40.0),
+            button(glfw::MouseButtonRight, glfw::Action::Release),
+        ];
+        for event in &events {
+            platform.handle_event(&mut imgui_context, event);
+        }
+        let mut frames = Vec::new();
+        for _ in 0..3 {
+            let io = imgui_context.io_mut();
+            platform.update_mouse(io);
+            frames.push((io.mouse_pos, io.mouse_down[0], io.mouse_down[1]));
+        }
+        assert_eq!(frames, [([10.0, 20.0], true, true), ([30.0, 40.0], false, false), ([30.0, 40.0], false, false)]);
+    }
+
+    #[test]
+    fn forgets_the_mouse_when_leaving_or_losing_focus() {
+        let _lock = lock_globals();
+        let (mut imgui_context, mut platform) = init_platform();
+        for event in [glfw::WindowEvent::CursorPos(10.0, 20.0), button(glfw::MouseButtonMiddle, glfw::Action::Press)] {
+            platform.handle_event(&mut imgui_context, &event);
+        }
+        platform.update_mouse(imgui_context.io_mut());
+        assert!(imgui_context.io().mouse_down[2]);
+        for event in [glfw::WindowEvent::CursorEnter(false), glfw::WindowEvent::Focus(false)] {
+            platform.handle_event(&mut imgui_context, &event);
+        }
+        platform.update_mouse(imgui_context.io_mut());
+        assert_eq!(imgui_context.io().mouse_pos, MOUSE_POSITION_UNKNOWN);
+        assert!(!imgui_context.io().mouse_down[2]);
+    }
+
+    #[test]
+    fn keeps_every_character_typed_within_a_frame() {
+        with_headless_app(|app| {
+            let click = [glfw::WindowEvent::CursorPos(60.0, 40.0), button(glfw::MouseButtonLeft, glfw::Action::Press)];
+            let release = [button(glfw::MouseButtonLeft, glfw::Action::Release)];
+            let burst : Vec<_> = "pasted text".chars().map(glfw::WindowEvent::Char).collect();
+            let mut text = String::new();
+            for events in [&[][..], &click, &release, &burst, &[]] {
+                app.frame(events, |ui| {
+                    fixed_window("input").build(ui, || {
+                        ui.input_text("text", &mut text).build();
+                    });
+                });
+            }
+            assert_eq!(text, "pasted text");
+        });
+    }
+
+    #[test]
+    fn copies_and_pastes_through_the_clipboard() {
+        with_headless_app(|app| {
+            app.clipboard().set_contents("pasted");
+            let key = |key, action| glfw::WindowEvent::Key(key, 0, action, glfw::Modifiers::empty());
+            let click = |y| [glfw::WindowEvent::CursorPos(60.0, y), button(glfw::MouseButtonLeft, glfw::Action::Press)];
+            let release = [button(glfw::MouseButtonLeft, glfw::Action::Release)];
+            let paste = [key(glfw::Key::LeftControl, glfw::Action::Press), key(glfw::Key::V, glfw::Action::Press)];
+            let paste_done = [key(glfw::Key::V, glfw::Action::Release), key(glfw::Key::LeftControl, glfw::Action::Release)];
+            let select_all = [key(glfw::Key::LeftControl, glfw::Action::Press), key(glfw::Key::A, glfw::Action::Press)];
+            let copy = [key(glfw::Key::A, glfw::Action::Release), key(glfw::Key::C, glfw::Action::Press)];
+            let mut text = String::from("copied");
+            let mut target = String::new();
+            let steps : [&[glfw::WindowEvent]; 9] = [&[], &click(40.0), &release, &paste, &paste_done, &click(64.0), &release, &select_all, &copy];
+            for events in steps {
+                app.frame(events, |ui| {
+                    fixed_window("input").build(ui, || {
+                        ui.input_text("target", &mut target).build();
+                        ui.input_text("text", &mut text).build();
+                    });
+                });
+            }
+            assert_eq!(target, "pasted");
+            assert_eq!(app.clipboard().contents().as_deref(), Some("copied"));
+        });
+    }
+
+    #[test]
+    fn scrolls_horizontally_and_forwards_extra_buttons() {
+        with_headless_app(|app| {
+            let scroll = [
+                glfw::WindowEvent::CursorPos(100.0, 100.0),
+                button(glfw::MouseButton::Button4, glfw::Action::Press),
+                glfw::WindowEvent::Scroll(-1.0, 0.0),
+                glfw::WindowEvent::Scroll(-1.0, 0.0),
+            ];
+            let mut scroll_x = 0.0;
+            for events in [&[][..], &[], &scroll, &[]] {
+                app.frame(events, |ui| {
+                    fixed_window("wide").horizontal_scrollbar(true).build(ui, || {
+                        ui.text("a line much wider than the window, so that it needs a horizontal scrollbar to be read in full");
+                        scroll_x = ui.scroll_x();
+                    });
+                });
+            }
+            assert!(scroll_x > 0.0);
+            assert!(app.imgui_context().io().mouse_down[imgui::MouseButton::Extra1 as usize]);
+        });
+    }
+
+    #[test]
+    fn sees_clicks_shorter_than_a_frame() {
+        with_headless_app(|app| {
+            let click = [
+                glfw::WindowEvent::CursorPos(20.0, 40.0),
+                button(glfw::MouseButtonLeft, glfw::Action::Press),
+                button(glfw::MouseButtonLeft, glfw::Action::Release),
+            ];
+            let mut checked = false;
+            for events in [&[][..], &click, &[], &[]] {
+                app.frame(events, |ui| {
+                    fixed_window("input").build(ui, || {
+                        ui.checkbox("checked", &mut checked);
+                    });
+                });
+            }
+            assert!(checked);
+            assert!(!app.imgui_context().io().mouse_down[0]);
+        });
+    }
+}
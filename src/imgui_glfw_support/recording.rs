@@ -0,0 +1,281 @@
+//! Records GLFW input events to a text file and plays them back frame by frame.
+//!
+//! Every line holds one event: the frame it arrived in, seconds since recording started,
+//! the event kind and its arguments, e.g.
+//!
+//! ```text
+//! 0 0.0000 size 1280 720
+//! 12 0.2013 cursor 104.5 37
+//! 12 0.2013 button 0 press 0
+//! 15 0.2514 key A 38 press 2
+//! 15 0.2514 char 97
+//! 19 0.3180 scroll 0 -1
+//! 20 0.3346 focus 0
+//! 24 0.4012 end
+//! ```
+//!
+//! The first line holds the window size the session started with, `end` the number of frames
+//! it ran, so frames without input at the end are played back as well. Recordings cut short
+//! without `end` stop after the last event.
+//!
+//! Playback only relies on the frame numbers and runs with a fixed `delta_time`, so a replayed
+//! session behaves the same no matter how fast the machine is. Other events are not recorded.
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::time::Instant;
+use glfw::{Action, Modifiers, MouseButton, WindowEvent};
+use super::keys::ALL_KEYS;
+use crate::error::Error;
+
+const HEADER: &str = "# imgui glfw input recording v1";
+
+fn action_name(action : Action) -> &'static str {
+    match action {
+        Action::Press => "press",
+        Action::Release => "release",
+        Action::Repeat => "repeat",
+    }
+}
+
+fn parse_action(name : &str) -> Result<Action, String> {
+    match name {
+        "press" => Ok(Action::Press),
+        "release" => Ok(Action::Release),
+        "repeat" => Ok(Action::Repeat),
+        _ => Err(format!("unknown action '{}'", name)),
+    }
+}
+
+/// Formats the arguments of an event, `None` for events that aren't recorded.
+fn format_event(event : &WindowEvent) -> Option<String> {
+    match event {
+        WindowEvent::Key(key, scancode, action, modifiers) => Some(format!("key {:?} {} {} {}", key, scancode, action_name(*action), modifiers.bits())),
+        WindowEvent::Char(character) => Some(format!("char {}", *character as u32)),
+        WindowEvent::Scroll(x, y) => Some(format!("scroll {} {}", x, y)),
+        WindowEvent::MouseButton(button, action, modifiers) => Some(format!("button {} {} {}", *button as i32, action_name(*action), modifiers.bits())),
+        WindowEvent::CursorPos(x, y) => Some(format!("cursor {} {}", x, y)),
+        WindowEvent::CursorEnter(entered) => Some(format!("enter {}", *entered as u8)),
+        WindowEvent::Focus(focused) => Some(format!("focus {}", *focused as u8)),
+        WindowEvent::Size(width, height) => Some(format!("size {} {}", width, height)),
+        _ => None,
+    }
+}
+
+fn parse_event(kind : &str, args : &[&str]) -> Result<WindowEvent, String> {
+    fn number<T : std::str::FromStr>(args : &[&str], index : usize) -> Result<T, String> {
+        let arg = args.get(index).ok_or_else(|| format!("missing argument {}", index + 1))?;
+        arg.parse().map_err(|_| format!("invalid number '{}'", arg))
+    }
+    match kind {
+        "key" => {
+            let name = args.first().ok_or("missing key name")?;
+            let key = ALL_KEYS.iter().copied().find(|key| format!("{:?}", key) == *name).ok_or_else(|| format!("unknown key '{}'", name))?;
+            let action = parse_action(args.get(2).ok_or("missing key action")?)?;
+            Ok(WindowEvent::Key(key, number(args, 1)?, action, Modifiers::from_bits_truncate(number(args, 3)?)))
+        },
+        "char" => {
+            let code : u32 = number(args, 0)?;
+            let character = char::from_u32(code).ok_or_else(|| format!("invalid character {}", code))?;
+            Ok(WindowEvent::Char(character))
+        },
+        "scroll" => Ok(WindowEvent::Scroll(number(args, 0)?, number(args, 1)?)),
+        "button" => {
+            let button = MouseButton::from_i32(number(args, 0)?).ok_or("unknown mouse button")?;
+            let action = parse_action(args.get(1).ok_or("missing button action")?)?;
+            Ok(WindowEvent::MouseButton(button, action, Modifiers::from_bits_truncate(number(args, 2)?)))
+        },
+        "cursor" => Ok(WindowEvent::CursorPos(number(args, 0)?, number(args, 1)?)),
+        "enter" => Ok(WindowEvent::CursorEnter(number::<u8>(args, 0)? != 0)),
+        "focus" => Ok(WindowEvent::Focus(number::<u8>(args, 0)? != 0)),
+        "size" => Ok(WindowEvent::Size(number(args, 0)?, number(args, 1)?)),
+        _ => Err(format!("unknown event '{}'", kind)),
+    }
+}
+
+pub struct InputRecorder<W : Write> {
+    writer : W,
+    start : Instant,
+    frame : u64
+}
+
+impl<W : Write> InputRecorder<W> {
+    /// `window_size` is the size in window coordinates the session starts with.
+    pub fn new(mut writer : W, window_size : (i32, i32)) -> io::Result<Self> {
+        writeln!(writer, "{}", HEADER)?;
+        let mut recorder = InputRecorder { writer, start: Instant::now(), frame: 0 };
+        recorder.record(&WindowEvent::Size(window_size.0, window_size.1))?;
+        Ok(recorder)
+    }
+    /// Call with every event of the frame, events that don't affect imgui are skipped.
+    pub fn record(&mut self, event : &WindowEvent) -> io::Result<()> {
+        match format_event(event) {
+            Some(line) => writeln!(self.writer, "{} {:.4} {}", self.frame, self.start.elapsed().as_secs_f64(), line),
+            None => Ok(()),
+        }
+    }
+    /// Marks the end of a frame, following events belong to the next one.
+    pub fn next_frame(&mut self) {
+        self.frame += 1;
+    }
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+    /// Writes the `end` line after the frames marked with `next_frame` so far.
+    pub fn finish(mut self) -> io::Result<W> {
+        writeln!(self.writer, "{} {:.4} end", self.frame, self.start.elapsed().as_secs_f64())?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// A line of an input recording that can't be played back.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line : usize,
+    pub message : String
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct InputPlayer {
+    /// Only frames with events, recordings can name any frame number.
+    frames : BTreeMap<usize, Vec<WindowEvent>>,
+    frame_count : usize,
+    next_frame : usize,
+    delta_time : f32
+}
+
+impl InputPlayer {
+    pub fn parse(recording : &str, delta_time : f32) -> Result<InputPlayer, ParseError> {
+        let mut frames : BTreeMap<usize, Vec<WindowEvent>> = BTreeMap::new();
+        let mut frame_count : usize = 0;
+        let mut end = None;
+        for (line_index, line) in recording.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message : String| ParseError { line: line_index + 1, message };
+            // `None` for the end line
+            let parse_line = || -> Result<(usize, Option<WindowEvent>), String> {
+                let fields : Vec<&str> = line.split_whitespace().collect();
+                if fields.len() < 3 {
+                    return Err("expected frame, timestamp and event".to_string());
+                }
+                let frame = fields[0].parse().map_err(|_| format!("invalid frame '{}'", fields[0]))?;
+                fields[1].parse::<f64>().map_err(|_| format!("invalid timestamp '{}'", fields[1]))?;
+                match fields[2] {
+                    "end" => Ok((frame, None)),
+                    kind => Ok((frame, Some(parse_event(kind, &fields[3..])?))),
+                }
+            };
+            let (frame, event) = parse_line().map_err(error)?;
+            if end.is_some() {
+                return Err(error("the recording already ended".to_string()));
+            }
+            if frame < frame_count.saturating_sub(1) {
+                return Err(error(format!("frame {} is out of order", frame)));
+            }
+            let event = match event {
+                Some(event) => event,
+                None => {
+                    end = Some(frame);
+                    continue;
+                }
+            };
+            frame_count = frame.checked_add(1).ok_or_else(|| error(format!("frame {} is too large", frame)))?;
+            frames.entry(frame).or_default().push(event);
+        }
+        // frames without events up to the end
+        let frame_count = end.map_or(frame_count, |end| end.max(frame_count));
+        Ok(InputPlayer { frames, frame_count, next_frame: 0, delta_time })
+    }
+    pub fn load<P : AsRef<std::path::Path>>(path : P, delta_time : f32) -> crate::error::Result<InputPlayer> {
+        let recording = std::fs::read_to_string(path.as_ref()).map_err(Error::io(format!("failed to read recording {}", path.as_ref().display())))?;
+        InputPlayer::parse(&recording, delta_time).map_err(Error::Recording)
+    }
+    /// Events of the next frame, `None` once the recording is exhausted.
+    pub fn next_frame(&mut self) -> Option<&[WindowEvent]> {
+        if self.next_frame >= self.frame_count {
+            return None;
+        }
+        let events = self.frames.get(&self.next_frame).map_or(&[][..], Vec::as_slice);
+        self.next_frame += 1;
+        Some(events)
+    }
+    pub fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+    pub fn delta_time(&self) -> f32 {
+        self.delta_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glfw::Key;
+    use super::*;
+
+    #[test]
+    fn recordings_round_trip() {
+        let events = [
+            vec![WindowEvent::CursorPos(104.5, 37.0), WindowEvent::MouseButton(glfw::MouseButtonLeft, Action::Press, Modifiers::empty())],
+            vec![],
+            vec![WindowEvent::Key(Key::A, 38, Action::Press, Modifiers::Control), WindowEvent::Char('ä'), WindowEvent::Focus(false), WindowEvent::Refresh],
+            vec![WindowEvent::Scroll(0.0, -1.0), WindowEvent::Key(Key::KpEnter, 104, Action::Release, Modifiers::empty()), WindowEvent::Size(640, 480)],
+            vec![],
+            vec![],
+        ];
+        let mut recorder = InputRecorder::new(Vec::new(), (800, 600)).unwrap();
+        for frame in &events {
+            for event in frame {
+                recorder.record(event).unwrap();
+            }
+            recorder.next_frame();
+        }
+        let recording = String::from_utf8(recorder.finish().unwrap()).unwrap();
+
+        let mut player = InputPlayer::parse(&recording, 1.0 / 60.0).unwrap();
+        // the frames without input at the end are kept
+        assert_eq!(player.frame_count(), 6);
+        // the window size the recording started with comes first
+        let first_frame = player.next_frame().unwrap();
+        assert_eq!(first_frame[0], WindowEvent::Size(800, 600));
+        assert_eq!(&first_frame[1..], &events[0][..]);
+        assert_eq!(player.next_frame().unwrap(), &[][..]);
+        // refresh events don't affect imgui and are not recorded
+        assert_eq!(player.next_frame().unwrap(), &events[2][..3]);
+        assert_eq!(player.next_frame().unwrap(), &events[3][..]);
+        assert_eq!(player.next_frame().unwrap(), &[][..]);
+        assert_eq!(player.next_frame().unwrap(), &[][..]);
+        assert!(player.next_frame().is_none());
+    }
+
+    #[test]
+    fn reports_the_offending_line() {
+        let error = InputPlayer::parse("# comment\n0 0.0 cursor 1 2\n1 0.1 key NoSuchKey 0 press 0\n", 0.1).err().unwrap();
+        assert_eq!(error, ParseError { line: 3, message: "unknown key 'NoSuchKey'".to_string() });
+        let error = InputPlayer::parse("3 0.0 char 97\n1 0.1 char 98\n", 0.1).err().unwrap();
+        assert_eq!(error.to_string(), "line 2: frame 1 is out of order");
+        let error = InputPlayer::parse("0 0.0 char 97\n2 0.1 end\n2 0.1 char 98\n", 0.1).err().unwrap();
+        assert_eq!(error.to_string(), "line 3: the recording already ended");
+        let error = InputPlayer::parse(&format!("0 0.0 char 97\n{} 0.1 char 98\n", usize::MAX), 0.1).err().unwrap();
+        assert_eq!(error.to_string(), format!("line 2: frame {} is too large", usize::MAX));
+    }
+
+    #[test]
+    fn skips_long_gaps_without_allocating_them() {
+        let mut player = InputPlayer::parse("0 0.0 char 97\n100000000000 0.1 char 98\n100000000002 0.2 end\n", 0.1).unwrap();
+        assert_eq!(player.frame_count(), 100000000002);
+        assert_eq!(player.next_frame().unwrap(), &[WindowEvent::Char('a')][..]);
+        assert_eq!(player.next_frame().unwrap(), &[][..]);
+    }
+}
@@ -0,0 +1,33 @@
+//! Dear ImGui on top of [`bgfx`](bgfx_rs) with [`glfw`] input.
+//!
+//! [`Renderer`] draws imgui's draw data with bgfx, [`GlfwPlatform`] feeds GLFW window
+//! events, display size and timing to imgui. See `examples/demo/main.rs` for a complete
+//! application loop.
+//!
+//! Every bgfx backend has its own cargo feature (`opengl`, `opengles`, `vulkan`, `metal`,
+//! `direct3d9` and `direct3d11`, all enabled by default) that embeds its imgui shaders.
+//! The Noop backend is always available, so the GLSL blobs it uses are embedded either way.
+//! The `software-rasterizer` feature adds `software_rasterizer`, a CPU renderer for golden
+//! image tests, and `testing` adds `headless`, which runs the UI on the Noop backend without
+//! a window.
+
+pub mod draw_capture;
+pub mod error;
+#[cfg(any(test, feature = "testing"))]
+pub mod headless;
+pub mod imgui_bgfx_renderer;
+pub mod imgui_glfw_support;
+#[cfg(feature = "software-rasterizer")]
+pub mod software_rasterizer;
+#[cfg(any(test, feature = "testing"))]
+#[doc(hidden)]
+pub mod test_support;
+
+pub use error::{Error, Result};
+pub use imgui_bgfx_renderer::{BgfxInstance, Renderer};
+pub use imgui_glfw_support::GlfwPlatform;
+
+// the public API is made of these crates' types, re-exported so users get matching versions
+pub use bgfx_rs;
+pub use glfw;
+pub use imgui;
@@ -0,0 +1,370 @@
+//! CPU reference rasterizer for imgui [`DrawData`].
+//!
+//! Consumes the draw data exactly like `imgui_bgfx_renderer::Renderer::render` does (clip rect
+//! to scissor conversion, vertex/index offsets, texture ids, alpha blending) and produces an
+//! RGBA image instead of bgfx draw calls, so the bookkeeping can be checked against golden
+//! images on machines without a GPU.
+use std::collections::HashMap;
+use std::path::Path;
+use imgui::{DrawCmd, DrawData, DrawIdx, DrawVert, TextureId};
+use crate::draw_capture::{command_indices, CapturedFrame};
+use crate::imgui_bgfx_renderer::{draw_lists, framebuffer_scissor, PLACEHOLDER_PIXELS};
+
+/// Clear color of the example's view 0.
+pub const DEFAULT_CLEAR_COLOR: [u8; 4] = [0x10, 0x30, 0x30, 0xFF];
+
+/// RGBA8 image, rows top to bottom. Always holds `width * height` pixels.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    width : u32,
+    height : u32,
+    pixels : Vec<u8>
+}
+
+impl Image {
+    pub fn new(width : u32, height : u32, color : [u8; 4]) -> Image {
+        Image { width, height, pixels: color.repeat(width as usize * height as usize) }
+    }
+    /// Fails unless `pixels` holds exactly `width * height` RGBA8 pixels.
+    pub fn from_pixels(width : u32, height : u32, pixels : Vec<u8>) -> Result<Image, String> {
+        let size = (width as usize).checked_mul(height as usize).and_then(|count| count.checked_mul(4));
+        if size != Some(pixels.len()) {
+            return Err(format!("{} bytes don't make a {}x{} RGBA8 image", pixels.len(), width, height));
+        }
+        Ok(Image { width, height, pixels })
+    }
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+    /// `None` outside of the image.
+    pub fn pixel(&self, x : u32, y : u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        self.pixels[offset..offset + 4].try_into().ok()
+    }
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        let mut data = Vec::new();
+        let mut encoder = png::Encoder::new(&mut data, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+        writer.write_image_data(&self.pixels).map_err(|e| e.to_string())?;
+        writer.finish().map_err(|e| e.to_string())?;
+        Ok(data)
+    }
+    /// Any PNG, converted to RGBA8.
+    pub fn from_png(data : &[u8]) -> Result<Image, String> {
+        let mut decoder = png::Decoder::new(data);
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().map_err(|e| e.to_string())?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer).map_err(|e| e.to_string())?;
+        buffer.truncate(info.buffer_size());
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer.chunks_exact(3).flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 0xFF]).collect(),
+            png::ColorType::GrayscaleAlpha => buffer.chunks_exact(2).flat_map(|ga| [ga[0], ga[0], ga[0], ga[1]]).collect(),
+            png::ColorType::Grayscale => buffer.iter().flat_map(|&g| [g, g, g, 0xFF]).collect(),
+            // expanded to RGB(A) by the transformations
+            png::ColorType::Indexed => return Err("unexpected indexed PNG".to_string()),
+        };
+        Image::from_pixels(info.width, info.height, pixels)
+    }
+    pub fn save_png<P : AsRef<Path>>(&self, path : P) -> std::io::Result<()> {
+        let data = self.to_png().map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, data)
+    }
+    pub fn load_png<P : AsRef<Path>>(path : P) -> Result<Image, String> {
+        let data = std::fs::read(path.as_ref()).map_err(|e| format!("{}: {}", path.as_ref().display(), e))?;
+        Image::from_png(&data)
+    }
+    /// Number of pixels where any channel differs by more than `tolerance`,
+    /// `None` if the images don't have the same size.
+    pub fn count_differences(&self, other : &Image, tolerance : u8) -> Option<usize> {
+        if self.width != other.width || self.height != other.height {
+            return None;
+        }
+        let differences = self.pixels.chunks(4).zip(other.pixels.chunks(4))
+            .filter(|(a, b)| a.iter().zip(b.iter()).any(|(a, b)| a.abs_diff(*b) > tolerance))
+            .count();
+        Some(differences)
+    }
+    /// Nearest-neighbour lookup with repeat wrapping, the bgfx default address mode.
+    /// Empty images sample as transparent black.
+    fn sample(&self, uv : [f32; 2]) -> [f32; 4] {
+        if self.width == 0 || self.height == 0 {
+            return [0.0; 4];
+        }
+        let x = ((uv[0] * self.width as f32).floor() as i64).rem_euclid(self.width as i64) as u32;
+        let y = ((uv[1] * self.height as f32).floor() as i64).rem_euclid(self.height as i64) as u32;
+        self.pixel(x, y).unwrap_or_default().map(|channel| channel as f32 / 255.0)
+    }
+}
+
+pub struct SoftwareRasterizer {
+    textures : HashMap<TextureId, Image>,
+    placeholder : Image,
+    clear_color : [u8; 4]
+}
+
+impl Default for SoftwareRasterizer {
+    fn default() -> Self {
+        SoftwareRasterizer::new()
+    }
+}
+
+impl SoftwareRasterizer {
+    pub fn new() -> Self {
+        SoftwareRasterizer {
+            textures: HashMap::new(),
+            placeholder: Image { width: 2, height: 2, pixels: PLACEHOLDER_PIXELS.concat() },
+            clear_color: DEFAULT_CLEAR_COLOR
+        }
+    }
+    pub fn set_clear_color(&mut self, color : [u8; 4]) {
+        self.clear_color = color;
+    }
+    /// CPU counterpart of `Renderer::register_texture`, using the id the renderer handed out.
+    pub fn register_texture(&mut self, texture_id : TextureId, image : Image) {
+        self.textures.insert(texture_id, image);
+    }
+    pub fn unregister_texture(&mut self, texture_id : TextureId) -> Option<Image> {
+        self.textures.remove(&texture_id)
+    }
+    /// Builds the font atlas and registers it under the atlas' current texture id.
+    pub fn register_font_atlas(&mut self, imgui_context : &mut imgui::Context) {
+        let mut fonts = imgui_context.fonts();
+        let texture_id = fonts.tex_id;
+        let atlas = fonts.build_rgba32_texture();
+        let image = Image { width: atlas.width, height: atlas.height, pixels: atlas.data.to_vec() };
+        self.textures.insert(texture_id, image);
+    }
+
+    pub fn rasterize(&self, draw_data : &DrawData) -> Image {
+        let mut target = self.clear_target(draw_data.display_size, draw_data.framebuffer_scale);
+        for draw_list in draw_lists(draw_data) {
+            self.rasterize_draw_list(&mut target, draw_data.display_pos, draw_data.framebuffer_scale, draw_list.vtx_buffer(), draw_list.idx_buffer(), draw_list.commands());
+        }
+        target
+    }
+    /// Draws a frame recorded with `draw_capture`, going through the same path as `rasterize`.
+    pub fn rasterize_captured(&self, frame : &CapturedFrame) -> Image {
+        let mut target = self.clear_target(frame.display_size, frame.framebuffer_scale);
+        for draw_list in &frame.draw_lists {
+            let commands = draw_list.commands.iter().map(|command| command.to_draw_cmd());
+            self.rasterize_draw_list(&mut target, frame.display_pos, frame.framebuffer_scale, &draw_list.vertices, &draw_list.indices, commands);
+        }
+        target
+    }
+    fn clear_target(&self, display_size : [f32; 2], framebuffer_scale : [f32; 2]) -> Image {
+        let fb_width = display_size[0] * framebuffer_scale[0];
+        let fb_height = display_size[1] * framebuffer_scale[1];
+        Image::new(fb_width.max(0.0) as u32, fb_height.max(0.0) as u32, self.clear_color)
+    }
+    fn rasterize_draw_list<I : Iterator<Item = DrawCmd>>(&self, target : &mut Image, clip_pos : [f32; 2], clip_scale : [f32; 2], vertices : &[DrawVert], indices : &[DrawIdx], commands : I) {
+        let fb_size = [target.width as f32, target.height as f32];
+        for command in commands {
+            if let DrawCmd::Elements { count, cmd_params } = command {
+                let scissor = match framebuffer_scissor(cmd_params.clip_rect, clip_pos, clip_scale, fb_size) {
+                    Some(scissor) => scissor,
+                    None => continue,
+                };
+                // the renderer leaves broken commands to bgfx, here they would index out of bounds
+                let triangle_indices = match command_indices(indices, vertices.len(), count, cmd_params.vtx_offset, cmd_params.idx_offset) {
+                    Some(triangle_indices) => triangle_indices,
+                    None => continue,
+                };
+                let texture = self.textures.get(&cmd_params.texture_id).unwrap_or(&self.placeholder);
+                for triangle in triangle_indices.chunks_exact(3) {
+                    let triangle = [triangle[0], triangle[1], triangle[2]].map(|index| {
+                        let vertex = vertices[cmd_params.vtx_offset + index as usize];
+                        let pos = [(vertex.pos[0] - clip_pos[0]) * clip_scale[0], (vertex.pos[1] - clip_pos[1]) * clip_scale[1]];
+                        DrawVert { pos, ..vertex }
+                    });
+                    draw_triangle(target, &triangle, texture, scissor);
+                }
+            }
+            // callbacks and render state resets have no CPU side effects to reproduce
+        }
+    }
+}
+
+fn edge(a : [f32; 2], b : [f32; 2], c : [f32; 2]) -> f32 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+/// Decides which of two triangles sharing an edge owns pixel centers lying exactly on it,
+/// so translucent quads don't get their diagonal blended twice.
+fn owns_edge(a : [f32; 2], b : [f32; 2]) -> bool {
+    let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+    dy > 0.0 || (dy == 0.0 && dx < 0.0)
+}
+
+fn draw_triangle(target : &mut Image, triangle : &[DrawVert; 3], texture : &Image, scissor : [u16; 4]) {
+    let [mut v0, mut v1, v2] = *triangle;
+    let mut area = edge(v0.pos, v1.pos, v2.pos);
+    if area == 0.0 {
+        return;
+    }
+    if area < 0.0 {
+        std::mem::swap(&mut v0, &mut v1);
+        area = -area;
+    }
+    let edges = [(v1.pos, v2.pos), (v2.pos, v0.pos), (v0.pos, v1.pos)];
+    let owned = edges.map(|(a, b)| owns_edge(a, b));
+
+    let min_x = v0.pos[0].min(v1.pos[0]).min(v2.pos[0]).floor().max(scissor[0] as f32) as u32;
+    let min_y = v0.pos[1].min(v1.pos[1]).min(v2.pos[1]).floor().max(scissor[1] as f32) as u32;
+    let max_x = (v0.pos[0].max(v1.pos[0]).max(v2.pos[0]).ceil() as u32).min(scissor[0] as u32 + scissor[2] as u32).min(target.width);
+    let max_y = (v0.pos[1].max(v1.pos[1]).max(v2.pos[1]).ceil() as u32).min(scissor[1] as u32 + scissor[3] as u32).min(target.height);
+
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let center = [x as f32 + 0.5, y as f32 + 0.5];
+            let weights = edges.map(|(a, b)| edge(a, b, center));
+            if (0..3).any(|i| weights[i] < 0.0 || (weights[i] == 0.0 && !owned[i])) {
+                continue;
+            }
+            let [w0, w1, w2] = weights.map(|weight| weight / area);
+            let uv = [
+                v0.uv[0] * w0 + v1.uv[0] * w1 + v2.uv[0] * w2,
+                v0.uv[1] * w0 + v1.uv[1] * w1 + v2.uv[1] * w2
+            ];
+            let texel = texture.sample(uv);
+            let mut source = [0.0f32; 4];
+            for (channel, value) in source.iter_mut().enumerate() {
+                let color = (v0.col[channel] as f32 * w0 + v1.col[channel] as f32 * w1 + v2.col[channel] as f32 * w2) / 255.0;
+                *value = texel[channel] * color;
+            }
+            // same blend state as the renderer: SRC_ALPHA, INV_SRC_ALPHA for color and alpha
+            let offset = (y as usize * target.width as usize + x as usize) * 4;
+            let alpha = source[3];
+            for (channel, value) in source.iter().enumerate() {
+                let destination = target.pixels[offset + channel] as f32 / 255.0;
+                let blended = value * alpha + destination * (1.0 - alpha);
+                target.pixels[offset + channel] = (blended * 255.0).round().clamp(0.0, 255.0) as u8;
+            }
+        }
+    }
+}
+
+/// Compares `image` against the PNG at `golden`, allowing for a handful of edge pixels that
+/// round differently across platforms. On a mismatch the image is written to `actual` for
+/// inspection. With `UPDATE_GOLDEN` set in the environment the golden image is rewritten instead.
+pub fn check_golden<P : AsRef<Path>, Q : AsRef<Path>>(golden : P, actual : Q, image : &Image) -> Result<(), String> {
+    let golden = golden.as_ref();
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        return image.save_png(golden).map_err(|e| format!("{}: {}", golden.display(), e));
+    }
+    let expected = Image::load_png(golden)?;
+    let differences = image.count_differences(&expected, 2).ok_or_else(|| format!("image size differs from {}", golden.display()))?;
+    if differences > (image.width * image.height / 1000) as usize {
+        let actual = actual.as_ref();
+        let _ = image.save_png(actual);
+        return Err(format!("{} pixels differ from {}, output written to {}", differences, golden.display(), actual.display()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use super::*;
+    use crate::draw_capture::{CapturedCommand, CapturedDrawList};
+    use crate::test_support::lock_globals;
+
+    const WIDTH: f32 = 1280.0;
+    const HEIGHT: f32 = 720.0;
+
+    fn golden_path(name : &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden").join(format!("{}.png", name))
+    }
+
+    /// Compares against `tests/golden/<name>.png`, run with `UPDATE_GOLDEN=1` to rewrite the references.
+    fn assert_matches_golden(name : &str, image : &Image) {
+        let actual = Path::new(env!("CARGO_MANIFEST_DIR")).join("target").join(format!("{}.actual.png", name));
+        if let Err(e) = check_golden(golden_path(name), &actual, image) {
+            panic!("{}", e);
+        }
+    }
+
+    fn render<F : FnMut(&imgui::Ui)>(frame_count : u32, mut build_ui : F) -> Image {
+        let mut imgui_context = imgui::Context::create();
+        imgui_context.set_ini_filename(None);
+        let mut rasterizer = SoftwareRasterizer::new();
+        rasterizer.register_font_atlas(&mut imgui_context);
+        let io = imgui_context.io_mut();
+        io.display_size = [WIDTH, HEIGHT];
+        io.delta_time = 1.0 / 60.0;
+        for _ in 1..frame_count {
+            build_ui(&imgui_context.frame());
+        }
+        let ui = imgui_context.frame();
+        build_ui(&ui);
+        rasterizer.rasterize(ui.render())
+    }
+
+    #[test]
+    fn demo_window_matches_golden() {
+        let _lock = lock_globals();
+        let mut open = true;
+        let image = render(3, |ui| ui.show_demo_window(&mut open));
+        assert_matches_golden("demo_window", &image);
+    }
+
+    #[test]
+    fn round_trips_png_and_reads_other_color_types() {
+        let width = 37;
+        let height = 23;
+        let pixels = (0..width * height * 4).map(|i| if i % 97 < 60 { 0x20 } else { (i * 7 % 251) as u8 }).collect();
+        let image = Image::from_pixels(width, height, pixels).unwrap();
+        assert_eq!(Image::from_png(&image.to_png().unwrap()).unwrap(), image);
+        assert!(Image::from_pixels(2, 2, vec![0; 15]).is_err());
+        assert!(Image::from_pixels(u32::MAX, u32::MAX, Vec::new()).is_err());
+        assert_eq!(image.pixel(width, 0), None);
+
+        let mut rgb = Vec::new();
+        let mut encoder = png::Encoder::new(&mut rgb, 2, 1);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.write_header().unwrap().write_image_data(&[1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(Image::from_png(&rgb).unwrap().pixels(), [1, 2, 3, 0xFF, 4, 5, 6, 0xFF]);
+    }
+
+    #[test]
+    fn unknown_texture_ids_draw_the_placeholder() {
+        let _lock = lock_globals();
+        let image = render(3, |ui| {
+            imgui::Window::new("image").position([0.0, 0.0], imgui::Condition::Always).build(ui, || {
+                imgui::Image::new(TextureId::new(0xDEAD), [64.0, 64.0]).build(ui);
+            });
+        });
+        let magenta = PLACEHOLDER_PIXELS[0];
+        let found = image.pixels().chunks(4).any(|pixel| pixel == magenta);
+        assert!(found, "placeholder texture not drawn");
+    }
+
+    #[test]
+    fn skips_commands_outside_of_their_draw_list() {
+        let vertex = DrawVert { pos: [0.0, 0.0], uv: [0.0, 0.0], col: [0xFF; 4] };
+        let mut frame = CapturedFrame { display_size: [4.0, 4.0], framebuffer_scale: [1.0, 1.0], ..Default::default() };
+        let elements = |count, vtx_offset, idx_offset| CapturedCommand::Elements {
+            count,
+            cmd_params: imgui::DrawCmdParams { clip_rect: [0.0, 0.0, 4.0, 4.0], texture_id: TextureId::new(0), vtx_offset, idx_offset }
+        };
+        frame.draw_lists.push(CapturedDrawList {
+            vertices: vec![vertex; 3],
+            indices: vec![0, 1, 2],
+            commands: vec![elements(3, 0, 1), elements(usize::MAX, 0, 1), elements(3, 1, 0), elements(3, usize::MAX, 0)]
+        });
+        let image = SoftwareRasterizer::new().rasterize_captured(&frame);
+        assert_eq!(image, Image::new(4, 4, DEFAULT_CLEAR_COLOR));
+    }
+}
@@ -0,0 +1,25 @@
+//! Helpers for the tests of this crate and of applications built on it.
+use std::sync::{Mutex, MutexGuard};
+use crate::headless::{init_bgfx_headless, HeadlessApp};
+
+static GLOBALS_LOCK: Mutex<()> = Mutex::new(());
+
+/// Dear ImGui keeps the current context in a global and bgfx is a process wide singleton,
+/// so tests touching either have to take this lock to run one at a time.
+pub fn lock_globals() -> MutexGuard<'static, ()> {
+    GLOBALS_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Runs `test` with a [`HeadlessApp`] on a fresh imgui context, holding [`lock_globals`] until
+/// bgfx is shut down again.
+pub fn with_headless_app<T, F : FnOnce(&mut HeadlessApp<'_>) -> T>(test : F) -> T {
+    let _lock = lock_globals();
+    let bgfx_instance = init_bgfx_headless().unwrap();
+    let mut app = HeadlessApp::new(&bgfx_instance, imgui::Context::create()).unwrap();
+    test(&mut app)
+}
+
+/// A 300x200 window in the top left corner, so tests can click its widgets at fixed positions.
+pub fn fixed_window(name : &str) -> imgui::Window<'_, &str> {
+    imgui::Window::new(name).position([0.0, 0.0], imgui::Condition::Always).size([300.0, 200.0], imgui::Condition::Always)
+}